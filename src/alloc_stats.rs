@@ -0,0 +1,185 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::runner::{self, Part};
+use crate::{AocError, Solution};
+
+/// Wraps the system allocator and counts what goes through it.
+/// Only installed with `--features count-allocs`, so normal builds don't pay for the atomics.
+pub struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+impl CountingAlloc {
+    fn add(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(size, Ordering::Relaxed);
+        let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn remove(size: usize) {
+        LIVE.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::remove(layout.size());
+    }
+
+    /// Counted as a fresh allocation of the new size, the way a copying realloc would be.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            Self::remove(layout.size());
+            Self::add(new_size);
+        }
+        new
+    }
+}
+
+pub const DISABLED: &str = "Allocation counting is off; build with --features count-allocs.";
+
+pub fn enabled() -> bool {
+    cfg!(feature = "count-allocs")
+}
+
+/// What one phase allocated.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct AllocStats {
+    pub allocations: usize,
+    pub bytes: usize,
+    /// The most heap the phase had live at once, on top of what was live when it started.
+    pub peak: usize,
+}
+
+/// Runs `f` and counts its allocations. Counts are global, so other threads allocating at the same time
+/// end up in the numbers too.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let result = f();
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+    (result, stats)
+}
+
+/// Bytes with a binary unit, e.g. `1.50 MiB`.
+fn human_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut amount = bytes as f64;
+    let mut unit = 0;
+    while amount >= 1024.0 && unit < UNITS.len() - 1 {
+        amount /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.2} {}", amount, UNITS[unit])
+    }
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:>12} {:>12} {:>12}", self.allocations, human_bytes(self.bytes), human_bytes(self.peak))
+    }
+}
+
+pub struct DayAllocs {
+    pub day: u8,
+    pub parse: AllocStats,
+    pub part_1: Option<AllocStats>,
+    pub part_2: Option<AllocStats>,
+}
+
+impl Display for DayAllocs {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Day {}", self.day)?;
+        writeln!(f, "{:<8} {:>12} {:>12} {:>12}", "phase", "allocations", "bytes", "peak")?;
+        writeln!(f, "{:<8} {}", "parse", self.parse)?;
+        if let Some(stats) = &self.part_1 {
+            writeln!(f, "{:<8} {}", "part 1", stats)?;
+        }
+        if let Some(stats) = &self.part_2 {
+            writeln!(f, "{:<8} {}", "part 2", stats)?;
+        }
+        Ok(())
+    }
+}
+
+fn allocs_with<S: Solution>(lines: &Vec<String>, part: Option<Part>) -> Result<DayAllocs, AocError> {
+    let (input, parse) = measure(|| S::parse(lines));
+    let input = input?;
+    let part_1 = (part != Some(Part::Two)).then(|| measure(|| S::part1(&input)).1);
+    let part_2 = (part != Some(Part::One)).then(|| measure(|| S::part2(&input)).1);
+    Ok(DayAllocs {
+        day: S::DAY,
+        parse,
+        part_1,
+        part_2,
+    })
+}
+
+/// Counts the allocations of parse, part 1 and part 2 of `day` on already-loaded input.
+pub fn alloc_day(day: u8, lines: &Vec<String>, part: Option<Part>) -> Result<DayAllocs, AocError> {
+    if !enabled() {
+        return Err(DISABLED.into());
+    }
+    runner::dispatch!(day, allocs_with(lines, part)).ok_or_else(|| runner::no_solution(day))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{human_bytes, AllocStats};
+
+    #[test]
+    fn test_display() {
+        assert_eq!(human_bytes(1000), "1000 B");
+        assert_eq!(human_bytes(1536), "1.50 KiB");
+        assert_eq!(human_bytes(3 << 30), "3.00 GiB");
+        let stats = AllocStats { allocations: 12, bytes: 2048, peak: 100 };
+        assert_eq!(stats.to_string(), "          12     2.00 KiB        100 B");
+    }
+
+    #[cfg(feature = "count-allocs")]
+    #[test]
+    fn test_measure() {
+        use std::hint::black_box;
+        use super::measure;
+
+        let (v, stats) = measure(|| black_box(vec![0u64; 1000]));
+        assert!(stats.allocations >= 1);
+        assert!(stats.bytes >= 8000);
+        assert!(stats.peak >= 8000);
+        drop(v);
+        let ((), stats) = measure(|| ());
+        assert_eq!(stats.allocations, 0);
+    }
+}
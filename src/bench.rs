@@ -0,0 +1,141 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use crate::runner::{self, Part};
+use crate::Solution;
+
+pub const DEFAULT_WARMUP: usize = 3;
+pub const DEFAULT_RUNS: usize = 20;
+
+/// Summary of repeated timings of one phase.
+#[derive(Clone, Copy, Debug)]
+pub struct Stats {
+    pub runs: usize,
+    pub mean: Duration,
+    pub std_dev: Duration,
+    pub min: Duration,
+    pub median: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let runs = samples.len();
+        if runs == 0 {
+            return Self {
+                runs,
+                mean: Duration::ZERO,
+                std_dev: Duration::ZERO,
+                min: Duration::ZERO,
+                median: Duration::ZERO,
+            };
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / runs as f64;
+        let variance = if runs > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (runs - 1) as f64
+        } else {
+            0.0
+        };
+        let median = if runs.is_multiple_of(2) {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        } else {
+            sorted[runs / 2]
+        };
+        Self {
+            runs,
+            mean: Duration::from_secs_f64(mean),
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+            min: sorted[0],
+            median,
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:>12} {:>12} {:>12} {:>12}",
+               format!("{:.2?}", self.mean),
+               format!("± {:.2?}", self.std_dev),
+               format!("{:.2?}", self.min),
+               format!("{:.2?}", self.median))
+    }
+}
+
+/// Calls `f` `warmup` times untimed, then `runs` times timed.
+pub fn measure<T>(warmup: usize, runs: usize, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..warmup {
+        black_box(f());
+    }
+    let samples: Vec<Duration> = (0..runs).map(|_| {
+        let start = Instant::now();
+        black_box(f());
+        start.elapsed()
+    }).collect();
+    Stats::from_samples(&samples)
+}
+
+pub struct DayBench {
+    pub day: u8,
+    pub parse: Stats,
+    pub part_1: Option<Stats>,
+    pub part_2: Option<Stats>,
+}
+
+impl Display for DayBench {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Day {} ({} runs)", self.day, self.parse.runs)?;
+        writeln!(f, "{:<8} {:>12} {:>12} {:>12} {:>12}", "phase", "mean", "σ", "min", "median")?;
+        writeln!(f, "{:<8} {}", "parse", self.parse)?;
+        if let Some(stats) = &self.part_1 {
+            writeln!(f, "{:<8} {}", "part 1", stats)?;
+        }
+        if let Some(stats) = &self.part_2 {
+            writeln!(f, "{:<8} {}", "part 2", stats)?;
+        }
+        Ok(())
+    }
+}
+
+fn bench_with<S: Solution>(lines: &Vec<String>, part: Option<Part>, warmup: usize, runs: usize) -> Result<DayBench, Box<dyn Error>> {
+    let input = S::parse(lines)?;
+    let parse = measure(warmup, runs, || S::parse(lines));
+    let part_1 = (part != Some(Part::Two)).then(|| measure(warmup, runs, || S::part1(&input)));
+    let part_2 = (part != Some(Part::One)).then(|| measure(warmup, runs, || S::part2(&input)));
+    Ok(DayBench {
+        day: S::DAY,
+        parse,
+        part_1,
+        part_2,
+    })
+}
+
+/// Times parse, part 1 and part 2 of `day` separately on already-loaded input.
+pub fn bench_day(day: u8, lines: &Vec<String>, part: Option<Part>, warmup: usize, runs: usize) -> Result<DayBench, Box<dyn Error>> {
+    runner::dispatch!(day, bench_with(lines, part, warmup, runs))
+        .ok_or_else(|| runner::no_solution(day))?
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::Stats;
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = [4, 2, 8, 6].iter().map(|&n| Duration::from_millis(n)).collect();
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        // Sample variance of 2, 4, 6, 8 ms is 20/3 ms².
+        let expected = (20.0f64 / 3.0).sqrt() / 1000.0;
+        assert!((stats.std_dev.as_secs_f64() - expected).abs() < 1e-9);
+
+        let odd = Stats::from_samples(&samples[..3]);
+        assert_eq!(odd.median, Duration::from_millis(4));
+    }
+}
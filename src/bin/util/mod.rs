@@ -1,4 +0,0 @@
-pub mod parsing;
-pub mod vecstuff;
-pub mod bitfutz;
-pub mod bytewise;
@@ -0,0 +1,262 @@
+use std::env;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::runner::Part;
+
+pub const YEAR: u16 = 2024;
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Where the session token is read from when `AOC_SESSION` isn't set. Kept out of git.
+pub const DEFAULT_SESSION_FILE: &str = ".aoc_session";
+const USER_AGENT: &str = "github.com/Zanderwohl/Advent-Of-Code-2024 by Alexander Lowry";
+
+/// Talks to the Advent of Code site, or anything pretending to be it at `base_url`.
+pub struct Client {
+    pub base_url: String,
+    session: String,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Fetched {
+    Downloaded,
+    /// The file was already there, so nothing was requested.
+    Cached,
+}
+
+/// What the site said about a submitted answer.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way.
+    Incorrect,
+    /// Submitted too soon after the last one; try again after this many seconds.
+    Wait(u64),
+    /// The part is already solved, so the answer wasn't checked.
+    AlreadySolved,
+    /// None of the above. Holds the response so it can be read.
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page the site returns for a submission.
+    pub fn parse(body: &str) -> Self {
+        if body.contains("That's the right answer") {
+            Verdict::Correct
+        } else if body.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else if body.contains("You gave an answer too recently") {
+            Verdict::Wait(wait_seconds(body).unwrap_or(60))
+        } else if body.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if body.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if body.contains("That's not the right answer") {
+            Verdict::Incorrect
+        } else {
+            Verdict::Unknown(body.to_string())
+        }
+    }
+}
+
+/// Reads "You have 1m 5s left to wait." as 65.
+fn wait_seconds(body: &str) -> Option<u64> {
+    let start = body.find("You have ")? + "You have ".len();
+    let end = start + body[start..].find(" left to wait")?;
+    body[start..end].split_whitespace().map(|amount| {
+        let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+        let number = number.parse::<u64>().ok()?;
+        match unit {
+            "h" => Some(number * 3600),
+            "m" => Some(number * 60),
+            "s" => Some(number),
+            _ => None,
+        }
+    }).sum()
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer!"),
+            Verdict::TooHigh => write!(f, "Wrong: too high."),
+            Verdict::TooLow => write!(f, "Wrong: too low."),
+            Verdict::Incorrect => write!(f, "Wrong."),
+            Verdict::Wait(seconds) => write!(f, "Too soon: wait {}s before submitting again.", seconds),
+            Verdict::AlreadySolved => write!(f, "Already solved; the answer wasn't checked."),
+            Verdict::Unknown(body) => write!(f, "Couldn't tell what the site said:\n{}", body),
+        }
+    }
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Uses `AOC_BASE_URL` and `AOC_SESSION` when they're set, falling back to the real site
+    /// and the token in `session_file`.
+    pub fn from_env(base_url: Option<&str>, session_file: Option<&Path>) -> Result<Self, Box<dyn Error>> {
+        let base_url = match base_url {
+            Some(url) => url.to_string(),
+            None => env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()),
+        };
+        let session = match (env::var("AOC_SESSION"), session_file) {
+            (Ok(session), None) => session,
+            (_, path) => {
+                let path = path.unwrap_or(Path::new(DEFAULT_SESSION_FILE));
+                fs::read_to_string(path).map_err(|e| format!(
+                    "No session token: set AOC_SESSION or put the session cookie in {} ({}).", path.display(), e))?
+            }
+        };
+        if session.trim().is_empty() {
+            return Err("The session token is empty.".into());
+        }
+        Ok(Self::new(&base_url, &session))
+    }
+
+    fn url(&self, day: u8, page: &str) -> String {
+        format!("{}/{}/day/{}{}", self.base_url, YEAR, day, page)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Downloads the day's input into `path`, unless `path` already exists.
+    pub fn fetch_input(&self, day: u8, path: &Path) -> Result<Fetched, Box<dyn Error>> {
+        if path.exists() {
+            return Ok(Fetched::Cached);
+        }
+        let url = self.url(day, "/input");
+        let response = ureq::get(&url)
+            .set("Cookie", &self.cookie())
+            .set("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(code, _) => format!("{} answered {} for {}.", self.base_url, code, url),
+                e => format!("Couldn't fetch the input: {}", e),
+            })?;
+        let body = response.into_string()?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        // Write somewhere else first so a failed write never leaves a half file that looks cached.
+        let partial = PathBuf::from(format!("{}.part", path.display()));
+        fs::write(&partial, body)?;
+        fs::rename(&partial, path)?;
+        Ok(Fetched::Downloaded)
+    }
+
+    /// Posts an answer for one part of a day.
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Verdict, Box<dyn Error>> {
+        let url = self.url(day, "/answer");
+        let level = part.to_string();
+        let response = ureq::post(&url)
+            .set("Cookie", &self.cookie())
+            .set("User-Agent", USER_AGENT)
+            .send_form(&[("level", &level), ("answer", answer)])
+            .map_err(|e| match e {
+                ureq::Error::Status(code, _) => format!("{} answered {} for {}.", self.base_url, code, url),
+                e => format!("Couldn't submit the answer: {}", e),
+            })?;
+        Ok(Verdict::parse(&response.into_string()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+    use super::{Client, Fetched, Verdict};
+    use crate::util::testing::scratch_dir;
+    use crate::runner::Part;
+
+    /// Serves one canned `(status, body)` per connection and hands back each request it saw.
+    fn stub_server(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        length = value.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                requests.push(request);
+                write!(reader.get_mut(), "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body).unwrap();
+            }
+            requests
+        });
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (base_url, server) = stub_server(vec![(200, "1 2\n3 4\n"), (404, "Not yet")]);
+        let client = Client::new(&format!("{}/", base_url), "abc123\n");
+        let dir = scratch_dir("fetch");
+        let path = dir.join("day_01_input.txt");
+
+        assert_eq!(client.fetch_input(1, &path).unwrap(), Fetched::Downloaded);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2\n3 4\n");
+        // The second call mustn't touch the server.
+        assert_eq!(client.fetch_input(1, &path).unwrap(), Fetched::Cached);
+
+        let missing = dir.join("day_25_input.txt");
+        let error = client.fetch_input(25, &missing).unwrap_err();
+        assert!(error.to_string().contains("404"), "{}", error);
+        assert!(!missing.exists());
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2024/day/1/input HTTP/1.1\r\n"), "{}", requests[0]);
+        assert!(requests[0].to_ascii_lowercase().contains("cookie: session=abc123\r\n"), "{}", requests[0]);
+        assert!(requests[1].starts_with("GET /2024/day/25/input "));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_verdict() {
+        let page = |text: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", text);
+        assert_eq!(Verdict::parse(&page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.")), Verdict::Correct);
+        assert_eq!(Verdict::parse(&page("That's not the right answer; your answer is too high.  If you're stuck, ...")), Verdict::TooHigh);
+        assert_eq!(Verdict::parse(&page("That's not the right answer; your answer is too low.")), Verdict::TooLow);
+        assert_eq!(Verdict::parse(&page("That's not the right answer.  If you're stuck, ...")), Verdict::Incorrect);
+        assert_eq!(Verdict::parse(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.")), Verdict::Wait(65));
+        assert_eq!(Verdict::parse(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 37s left to wait.")), Verdict::Wait(37));
+        assert_eq!(Verdict::parse(&page("You don't seem to be solving the right level.  Did you already complete it?")), Verdict::AlreadySolved);
+        assert!(matches!(Verdict::parse("502 Bad Gateway"), Verdict::Unknown(_)));
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, server) = stub_server(vec![(200, "<article><p>That's not the right answer; your answer is too low.</p></article>")]);
+        let client = Client::new(&base_url, "abc123");
+        assert_eq!(client.submit(13, Part::Two, "875318608908").unwrap(), Verdict::TooLow);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2024/day/13/answer HTTP/1.1\r\n"), "{}", requests[0]);
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=875318608908"), "{}", requests[0]);
+    }
+}
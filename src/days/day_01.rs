@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+use crate::util::parsing;
+use crate::{AocError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = (Vec<i32>, Vec<i32>);
+    type Answer = i64;
+
+    const DAY: u8 = 1;
+    const PART_1: &'static str = "Solution is";
+    const PART_2: &'static str = "Similarity is";
+
+    fn parse(lines: &Vec<String>) -> Result<Self::Input, AocError> {
+        let rows = parsing::parse_rows::<i32>(lines, None)?;
+        parsing::unzip_2(rows).map_err(|e| e.snippet_from(lines))
+    }
+
+    fn part1((a, b): &Self::Input) -> Self::Answer {
+        solve(a.clone(), b.clone())
+    }
+
+    fn part2((a, b): &Self::Input) -> Self::Answer {
+        similar(a.clone(), b.clone())
+    }
+}
+
+fn solve(mut left: Vec<i32>, mut right: Vec<i32>) -> i64 {
+    left.sort();
+    right.sort();
+
+    let mut distance = 0;
+    for idx in 0..right.len() {
+        let a = left[idx];
+        let b = right[idx];
+
+        distance += (a as i64 - b as i64).abs()
+    }
+    distance
+}
+
+fn similar(left: Vec<i32>, right: Vec<i32>) -> i64 {
+    let mut occurances: HashMap<i32, i64> = HashMap::with_capacity(left.len());
+
+    for item in left {
+        let count = right.iter().filter(|&n| *n == item).count() as i64;
+        *occurances.entry(item).or_insert(0) += count;
+    }
+    occurances.into_iter().map(|(number, occurrences)| {
+        number as i64 * occurrences
+    }).sum()
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::{similar, solve};
+
+    #[test]
+    fn part_one() {
+        let a = vec![3, 4, 2, 1, 3, 3];
+        let b = vec![4, 3, 5, 3, 9, 3];
+        let solution = solve(a, b);
+        assert_eq!(solution, 11);
+    }
+
+    #[test]
+    fn part_two() {
+        let a = vec![3, 4, 2, 1, 3, 3];
+        let b = vec![4, 3, 5, 3, 9, 3];
+        let similarity = similar(a, b);
+        assert_eq!(similarity, 31);
+    }
+}
@@ -0,0 +1,172 @@
+use itertools::Itertools;
+use crate::util::vecstuff;
+use crate::util::parsing::parse_rows;
+use crate::{AocError, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<i32>>;
+    type Answer = usize;
+
+    const DAY: u8 = 2;
+    const PART_1: &'static str = "Number of safe reports";
+    const PART_2: &'static str = "Number of safe dampened reports";
+
+    fn parse(lines: &Vec<String>) -> Result<Self::Input, AocError> {
+        parse_rows::<i32>(lines, None)
+    }
+
+    fn part1(reports: &Self::Input) -> Self::Answer {
+        count_safe(reports)
+    }
+
+    fn part2(reports: &Self::Input) -> Self::Answer {
+        count_dampened_safe(reports)
+    }
+}
+
+fn count_safe(reports: &Vec<Vec<i32>>) -> usize {
+    safeties(reports).iter().filter(|&n| *n).count()
+}
+
+fn safeties(reports: &Vec<Vec<i32>>) -> Vec<bool> {
+    reports.iter().map(|report| {
+        is_safe(report)
+    }).collect()
+}
+
+fn is_safe(report: &Vec<i32>) -> bool {
+    if report.len() < 2 {
+        return true;
+    }
+    let ascending = report[0] < report[1];
+    for (a, b) in report.iter().tuple_windows() {
+        let diff = a.abs_diff(*b);
+        let currently_ascending = a < b;
+        if ascending != currently_ascending {
+            return false;
+        }
+        if !(1..=3).contains(&diff) {
+            return false;
+        }
+    }
+    true
+}
+
+fn count_dampened_safe(reports: &Vec<Vec<i32>>) -> usize {
+    dampened_safeties(reports).iter().filter(|&n| *n).count()
+}
+
+fn dampened_safeties(reports: &Vec<Vec<i32>>) -> Vec<bool> {
+    reports.iter().map(|report| {
+        is_dampened_safe(report)
+    }).collect()
+}
+
+fn is_dampened_safe(report: &Vec<i32>) -> bool {
+    if is_safe(report) {
+        return true;
+    }
+    report.iter().enumerate().map(|(idx, _)| {
+        let sub_report = vecstuff::vec_without(report, idx);
+        is_safe(&sub_report)
+    }).filter(|&n| n).count() > 0
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::{count_safe, dampened_safeties, is_dampened_safe, safeties};
+
+    #[test]
+    fn test_safe() {
+        let reports = vec![
+            vec![7, 6, 4, 2, 1],
+            vec![1, 2, 7, 8, 9],
+            vec![9, 7, 6, 2, 1],
+            vec![1, 3, 2, 4, 5],
+            vec![8, 6, 4, 4, 1],
+            vec![1, 3, 6, 7, 9],
+        ];
+        let safeness = [
+            true,
+            false,
+            false,
+            false,
+            false,
+            true,
+        ];
+        let num_safe = 2;
+        let actual_safeties = safeties(&reports);
+        for idx in 0..6 {
+            assert_eq!(safeness[idx], actual_safeties[idx]);
+        }
+        assert_eq!(count_safe(&reports), num_safe);
+    }
+
+
+    #[test]
+    fn test_dampened_safe() {
+        let reports = vec![
+            vec![7, 6, 4, 2, 1],
+            vec![1, 2, 7, 8, 9],
+            vec![9, 7, 6, 2, 1],
+            vec![1, 3, 2, 4, 5],
+            vec![8, 6, 4, 4, 1],
+            vec![1, 3, 6, 7, 9],
+        ];
+        let safeness = [
+            true,
+            false,
+            false,
+            true,
+            true,
+            true,
+        ];
+        let num_safe = 2;
+        let actual_safeties = dampened_safeties(&reports);
+        for idx in 0..6 {
+            assert_eq!(safeness[idx], actual_safeties[idx]);
+        }
+        assert_eq!(count_safe(&reports), num_safe);
+    }
+
+    /// Straight from the puzzle text, without sharing anything with `is_safe`.
+    fn brute_force_safe(report: &[i32]) -> bool {
+        let increasing = report.windows(2).all(|w| (1..=3).contains(&(w[1] - w[0])));
+        let decreasing = report.windows(2).all(|w| (1..=3).contains(&(w[0] - w[1])));
+        increasing || decreasing
+    }
+
+    fn brute_force_dampened(report: &[i32]) -> bool {
+        brute_force_safe(report) || (0..report.len()).any(|skip| {
+            let rest: Vec<i32> = report.iter().enumerate().filter(|&(i, _)| i != skip).map(|(_, &level)| level).collect();
+            brute_force_safe(&rest)
+        })
+    }
+
+    /// Either any small levels, or a walk with steps of -4 to 4 that is often nearly safe.
+    fn reports() -> impl Strategy<Value = Vec<i32>> {
+        let random = prop::collection::vec(1..10i32, 1..9);
+        let walk = (1..20i32, prop::collection::vec(-4..=4i32, 0..8)).prop_map(|(start, steps)| {
+            steps.iter().scan(start, |level, step| {
+                *level += step;
+                Some(*level)
+            }).fold(vec![start], |mut report, level| {
+                report.push(level);
+                report
+            })
+        });
+        prop_oneof![random, walk]
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(5000))]
+
+        #[test]
+        fn dampened_safe_agrees_with_brute_force(report in reports()) {
+            prop_assert_eq!(is_dampened_safe(&report), brute_force_dampened(&report), "{:?}", report);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use std::cmp::min;
+use lazy_static::lazy_static;
+use regex::Regex;
+use crate::util::parsing::scan;
+use crate::{AocError, Solution};
+
+lazy_static! {
+    static ref EXPR: Regex = Regex::new(r"mul\(\d{1,3},\d{1,3}\)").unwrap();
+    static ref EXPR_B: Regex = Regex::new(r"^mul\(\d{1,3},\d{1,3}\)").unwrap();
+    static ref DO: Regex = Regex::new(r"^do\(\)").unwrap();
+    static ref DONT: Regex = Regex::new(r"^don't\(\)").unwrap();
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    type Answer = u64;
+
+    const DAY: u8 = 3;
+    const PART_1: &'static str = "The sum of all mults is";
+    const PART_2: &'static str = "The sum of mults with conditionals is";
+
+    fn parse(lines: &Vec<String>) -> Result<Self::Input, AocError> {
+        Ok(lines.clone())
+    }
+
+    fn part1(lines: &Self::Input) -> Self::Answer {
+        lines.iter().map(|line| {
+            eval_line(line)
+        }).sum()
+    }
+
+    fn part2(lines: &Self::Input) -> Self::Answer {
+        eval_do_dont(lines)
+    }
+}
+
+/// The product in a `mul(X,Y)`, or `None` if `expr` isn't one.
+pub fn eval_mul(expr: &str) -> Option<u64> {
+    let (first, second) = scan!(expr, "mul({},{})", u64, u64).ok()?;
+    first.checked_mul(second)
+}
+
+pub fn eval_line(line: &str) -> u64 {
+    EXPR.find_iter(line).map(|expr| {
+        let expr = expr.as_str();
+        eval_mul(expr).unwrap_or(0)
+    }).sum()
+}
+
+pub fn eval_do_dont(lines: &Vec<String>) -> u64 {
+    let mut active = true;
+    let mut sum = 0;
+    for line in lines {
+        let indices: Vec<usize> = line.char_indices().map(|(i, _)| i).collect();
+        let len = indices.len();
+        for idx in 0..len {
+            // The longest instruction, `mul(123,456)`, is 12 characters.
+            let from = indices[idx];
+            let to = indices.get(min(idx + 12, len)).copied().unwrap_or(line.len());
+            let slice = &line[from..to];
+            if DO.is_match(slice) {
+                active = true;
+            } else if DONT.is_match(slice) {
+                active = false;
+            } else if EXPR_B.is_match(slice) {
+                if let Some(captures) = EXPR_B.captures(slice) {
+                    let expr = &captures[0];
+                    if active {
+                        sum += eval_mul(expr).unwrap_or(0);
+                    }
+                }
+            }
+        }
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{eval_line, eval_do_dont, eval_mul};
+
+    #[test]
+    fn test_eval_mul() {
+        let pairs = [
+            ("mul(962,335)", 322270),
+            ("mul(73,181)", 13213),
+            ("mul(1,1)", 1),
+            ("mul(5,4)", 20),
+            ("mul(21,45)", 945),
+        ];
+        for (expr, expected) in &pairs {
+            let actual = eval_mul(expr);
+            assert_eq!(Some(*expected), actual);
+        }
+    }
+
+    #[test]
+    fn test_eval_line() {
+        let line = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+        let actual = eval_line(line);
+        assert_eq!(actual, 161);
+    }
+
+    #[test]
+    fn test_do_dont() {
+        let line = vec!["xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))".to_string()];
+        let actual = eval_do_dont(&line);
+        assert_eq!(actual, 48);
+    }
+}
\ No newline at end of file
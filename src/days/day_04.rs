@@ -0,0 +1,245 @@
+use std::fmt::{Display, Formatter};
+use crate::util::geom::Dir8;
+use crate::util::grid::Grid;
+use crate::{AocError, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Puzzle;
+    type Answer = usize;
+
+    const DAY: u8 = 4;
+    const PART_1: &'static str = "Instances of 'XMAS' in puzzle";
+    const PART_2: &'static str = "Instances of X-'MAS' in puzzle";
+
+    fn parse(lines: &Vec<String>) -> Result<Self::Input, AocError> {
+        Puzzle::new(lines)
+    }
+
+    fn part1(puzzle: &Self::Input) -> Self::Answer {
+        puzzle.entire_xmas_count()
+    }
+
+    fn part2(puzzle: &Self::Input) -> Self::Answer {
+        puzzle.entire_x_mas_count()
+    }
+}
+
+pub struct Puzzle {
+    text: Grid<char>,
+    width: i32,
+    height: i32,
+}
+
+impl Puzzle {
+    pub fn new(lines: &Vec<String>) -> Result<Self, AocError> {
+        let text = Grid::parse(lines, Some)?;
+        Ok(Self {
+            width: text.width() as i32,
+            height: text.height() as i32,
+            text,
+        })
+    }
+
+    pub fn get(&self, x: i32, y: i32) -> char {
+        self.text.get(x as isize, y as isize).copied().unwrap_or('.')
+    }
+
+    pub fn get_line(&self, x: i32, y: i32, dx: i32, dy: i32) -> [char; 4] {
+        [
+            self.get(x, y),
+            self.get(x + dx, y + dy),
+            self.get(x + dx * 2, y + dy * 2),
+            self.get(x + dx * 3, y + dy * 3),
+        ]
+    }
+
+    pub fn line_is_xmas(&self, x: i32, y: i32, dx: i32, dy: i32) -> bool {
+        let line = self.get_line(x, y, dx, dy);
+        line[0] == 'X' && line[1] == 'M' && line[2] == 'A' && line[3] == 'S'
+    }
+
+    pub fn count_at(&self, x: i32, y: i32) -> usize {
+        Dir8::all().filter(|dir| {
+            let offset = dir.offset();
+            self.line_is_xmas(x, y, offset.x as i32, offset.y as i32)
+        }).count()
+    }
+
+    pub fn entire_xmas_count(&self) -> usize {
+        (0..self.height).map(|y| {
+            (0..self.width).map(|x|{
+                self.count_at(x, y)
+            }).sum::<usize>()
+        }).sum::<usize>()
+    }
+
+    pub fn get_x(&self, x: i32, y: i32) -> [[char; 3]; 3] {
+        [
+            [
+                self.get(x - 1, y - 1),
+                self.get(x, y - 1),
+                self.get(x + 1, y - 1),
+            ],
+            [
+                self.get(x - 1, y),
+                self.get(x, y),
+                self.get(x + 1, y),
+            ],
+            [
+                self.get(x - 1, y + 1),
+                self.get(x, y + 1),
+                self.get(x + 1, y + 1),
+            ]
+        ]
+    }
+
+    pub fn x_is_mas(x: &[[char; 3]; 3]) -> bool {
+        if x[1][1] != 'A' {
+            return false
+        }
+        if !((x[0][0] == 'S' && x[2][2] == 'M') || (x[0][0] == 'M' && x[2][2] == 'S')) {
+            return false
+        }
+        if !((x[0][2] == 'S' && x[2][0] == 'M') || (x[0][2] == 'M' && x[2][0] == 'S')) {
+            return false
+        }
+        true
+    }
+
+    pub fn x_is_mas_at(&self, x: i32, y: i32) -> bool {
+        let x = self.get_x(x, y);
+        Self::x_is_mas(&x)
+    }
+
+    pub fn entire_x_mas_count(&self) -> usize {
+        (0..self.height).map(|y| {
+            (0..self.width).map(|x|{
+                self.x_is_mas_at(x, y) as usize
+            }).sum::<usize>()
+        }).sum::<usize>()
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}x{}", self.width, self.height)?;
+        writeln!(f, "{}", self.text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Puzzle;
+
+    #[test]
+    fn structure_puzzle() {
+        let puzzle = vec![
+            "MMMSXXMASM".to_string(),
+            "MSAMXMSMSA".to_string(),
+            "AMXSXMAAMM".to_string(),
+            "MSAMASMSMX".to_string(),
+            "XMASAMXAMM".to_string(),
+            "XXAMMXXAMA".to_string(),
+            "SMSMSASXSS".to_string(),
+            "SAXAMASAAA".to_string(),
+            "MAMMMXMMMM".to_string(),
+            "MXMXAXMASX".to_string()
+        ];
+        let puzzle = Puzzle::new(&puzzle).unwrap();
+        println!("{}", puzzle);
+    }
+
+    #[test]
+    fn test_basic_search() {
+        let puzzle = vec![
+            "MMMSXXMASM".to_string(),
+            "MSAMXMSMSA".to_string(),
+            "AMXSXMAAMM".to_string(),
+            "MSAMASMSMX".to_string(),
+            "XMASAMXAMM".to_string(),
+            "XXAMMXXAMA".to_string(),
+            "SMSMSASXSS".to_string(),
+            "SAXAMASAAA".to_string(),
+            "MAMMMXMMMM".to_string(),
+            "MXMXAXMASX".to_string()
+        ];
+        let puzzle = Puzzle::new(&puzzle).unwrap();
+        let actual = puzzle.entire_xmas_count();
+        assert_eq!(actual, 18);
+    }
+
+    #[test]
+    fn test_sparse_search() {
+        let puzzle = vec![
+            "....XXMAS.".to_string(),
+            ".SAMXMS...".to_string(),
+            "...S..A...".to_string(),
+            "..A.A.MS.X".to_string(),
+            "XMASAMX.MM".to_string(),
+            "X.....XA.A".to_string(),
+            "S.S.S.S.SS".to_string(),
+            ".A.A.A.A.A".to_string(),
+            "..M.M.M.MM".to_string(),
+            ".X.X.XMASX".to_string()
+        ];
+        let puzzle = Puzzle::new(&puzzle).unwrap();
+        let actual = puzzle.entire_xmas_count();
+        assert_eq!(actual, 18);
+    }
+
+    #[test]
+    fn test_one_x_mas() {
+        let puzzles = [
+            vec![
+              "M.M".to_string(),
+              ".A.".to_string(),
+              "S.S".to_string(),
+            ],
+            vec![
+                "M.S".to_string(),
+                ".A.".to_string(),
+                "M.S".to_string(),
+            ],
+            vec![
+                "S.M".to_string(),
+                ".A.".to_string(),
+                "S.M".to_string(),
+            ],
+            vec![
+                "S.S".to_string(),
+                ".A.".to_string(),
+                "M.M".to_string(),
+            ],
+        ];
+        for puzzle in puzzles {
+            let puzzle = Puzzle::new(&puzzle).unwrap();
+            let actual = puzzle.entire_x_mas_count();
+            let x = puzzle.get_x(1, 1);
+            println!("{:?}", x);
+            assert!(puzzle.x_is_mas_at(1, 1));
+            assert_eq!(actual, 1);
+        }
+
+    }
+
+    #[test]
+    fn test_x_mas_dense() {
+        let puzzle = vec![
+            "MMMSXXMASM".to_string(),
+            "MSAMXMSMSA".to_string(),
+            "AMXSXMAAMM".to_string(),
+            "MSAMASMSMX".to_string(),
+            "XMASAMXAMM".to_string(),
+            "XXAMMXXAMA".to_string(),
+            "SMSMSASXSS".to_string(),
+            "SAXAMASAAA".to_string(),
+            "MAMMMXMMMM".to_string(),
+            "MXMXAXMASX".to_string()
+        ];
+        let puzzle = Puzzle::new(&puzzle).unwrap();
+        let actual = puzzle.entire_x_mas_count();
+        assert_eq!(actual, 9);
+    }
+}
\ No newline at end of file
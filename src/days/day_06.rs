@@ -0,0 +1,350 @@
+use std::cmp::PartialEq;
+use std::fmt::{Display, Formatter};
+use crate::render::{Canvas, BACKGROUND};
+use crate::util::geom::Dir4;
+use crate::util::grid::Grid;
+use crate::util::log::{debug, trace};
+use crate::util::span;
+use crate::{AocError, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Map;
+    type Answer = Count;
+
+    const DAY: u8 = 6;
+    const PART_1: &'static str = "The number of unique spaces the guard visited was";
+    const PART_2: &'static str = "And the number of candidate obstacle locations is";
+
+    fn parse(lines: &Vec<String>) -> Result<Self::Input, AocError> {
+        parse_map(lines)
+    }
+
+    fn part1(map: &Self::Input) -> Self::Answer {
+        run_map(map).map_or(Count::NoExit, |new_map| Count::Of(count_visited_map(&new_map)))
+    }
+
+    fn part2(map: &Self::Input) -> Self::Answer {
+        let new_map = {
+            let _span = span::enter("run_map");
+            run_map(map)
+        };
+        let Some(new_map) = new_map else {
+            return Count::NoExit;
+        };
+        let guess = count_visited_map(&new_map);
+        Count::Of(find_obstacle_locations(map, &new_map, guess))
+    }
+}
+
+/// Both parts count something about the guard's way out, so a guard who loops from the start leaves nothing to count.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Count {
+    Of(usize),
+    NoExit,
+}
+
+impl Display for Count {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Count::Of(n) => write!(f, "{}", n),
+            Count::NoExit => write!(f, "nothing, since the guard never leaves the map"),
+        }
+    }
+}
+
+/// Walks the guard until they leave the map, or `None` if they start to loop instead.
+pub(crate) fn run_map(map: &Map) -> Option<Map> {
+    let mut modified_map = map.clone();
+    loop {
+        match step_map(&mut modified_map) {
+            (false, _) => return Some(modified_map),
+            (true, true) => return None,
+            (true, false) => {},
+        }
+    }
+}
+
+fn does_map_loop(map: &mut Map) -> bool {
+    let mut running = true;
+    let mut looping = false;
+    while running && !looping {
+        (running, looping) = step_map(map);
+    }
+    looping
+}
+
+pub(crate) fn count_visited_map(map: &Map) -> usize {
+    map.cells.iter().filter(|(_, cell)| cell.same(&Cell::Visited(VisitHistory::default()))).count()
+}
+
+pub fn print_map(map: &Map) {
+    println!("{}", map.cells);
+}
+
+/// Moves or turns the guard once. Returns whether they're still on the map and whether they've started to loop.
+pub(crate) fn step_map(map: &mut Map) -> (bool, bool) {
+    let (x, y) = (map.guard.x, map.guard.y);
+    let Some(cell) = map.cells.get_mut(x, y) else {
+        return (false, false);
+    };
+    if *cell == Cell::Unvisited {
+        *cell = Cell::Visited(VisitHistory::default());
+    }
+    if let Cell::Visited(history) = cell {
+        history.add(&map.guard.dir)
+    }
+
+    let (dx, dy) = map.guard.next_action();
+    match map.cells.get(x + dx, y + dy) {
+        None => (false, false),
+        Some(Cell::Unvisited) => {
+            map.guard.x += dx;
+            map.guard.y += dy;
+            (true, false)
+        },
+        Some(Cell::Visited(visit_history)) => {
+            let looping = visit_history.has(&map.guard.dir);
+            map.guard.x += dx;
+            map.guard.y += dy;
+            (true, looping)
+        },
+        Some(Cell::Crate | Cell::Obstruction) => {
+            map.guard.turn();
+            // Facing a way they've faced here before means they're going round again, even if only on the spot.
+            let looping = matches!(&map.cells[(x as usize, y as usize)], Cell::Visited(history) if history.has(&map.guard.dir));
+            (true, looping)
+        },
+    }
+}
+
+fn coarse_candidate_obstacles(original_run: &Map, unique_positions: usize) -> Vec<(usize, usize)> {
+    debug!("Counting candidates...");
+    let mut candidates = Vec::with_capacity(unique_positions);
+    for (position, cell) in original_run.cells.iter() {
+        if cell.same(&Cell::Visited(Default::default())) {
+            candidates.push(position);
+        }
+    }
+    debug!("Found {} candidates.", candidates.len());
+    candidates
+}
+
+fn find_obstacle_locations(fresh_map: &Map, original_run: &Map, unique_positions: usize) -> usize {
+    loop_obstacles(fresh_map, original_run, unique_positions).len()
+}
+
+/// Where one more obstruction would make the guard loop.
+fn loop_obstacles(fresh_map: &Map, original_run: &Map, unique_positions: usize) -> Vec<(usize, usize)> {
+    let candidates = {
+        let _span = span::enter("coarse_candidate_obstacles");
+        coarse_candidate_obstacles(original_run, unique_positions)
+    };
+    let len = candidates.len();
+    debug!("Trying {} locations!", len);
+    let _span = span::enter("does_map_loop");
+    candidates.into_iter().enumerate().filter(|&(idx, (x, y))| {
+        let mut map = fresh_map.clone();
+        map.cells[(x, y)] = Cell::Obstruction;
+        let loops = does_map_loop(&mut map);
+        trace!("\t#{}/{} at ({}, {}) loops? {}", idx + 1, len, x, y, loops);
+        loops
+    }).map(|(_, position)| position).collect()
+}
+
+/// The guard's path in blue from their start in yellow, and in red the places an obstruction would make them loop.
+/// Also returns how many of those there are, which is the answer to part 2.
+pub fn render(map: &Map) -> (Canvas, Count) {
+    let mut canvas = Canvas::new(map.cells.width(), map.cells.height(), BACKGROUND);
+    let mut obstacles = Count::NoExit;
+    if let Some(run) = run_map(map) {
+        for ((x, y), cell) in run.cells.iter() {
+            match cell {
+                Cell::Visited(_) => canvas.set(x, y, [70, 130, 220]),
+                Cell::Crate => canvas.set(x, y, [150, 150, 150]),
+                Cell::Obstruction => canvas.set(x, y, [220, 220, 220]),
+                Cell::Unvisited => {},
+            }
+        }
+        let loops = loop_obstacles(map, &run, count_visited_map(&run));
+        for &(x, y) in &loops {
+            canvas.set(x, y, [230, 60, 50]);
+        }
+        obstacles = Count::Of(loops.len());
+    }
+    canvas.set(map.guard.x as usize, map.guard.y as usize, [250, 210, 60]);
+    (canvas, obstacles)
+}
+
+#[derive(Clone)]
+pub struct Map {
+    pub(crate) cells: Grid<Cell>,
+    pub(crate) guard: Guard,
+}
+
+pub(crate) fn parse_map(lines: &Vec<String>) -> Result<Map, AocError> {
+    let (cells, guards) = Grid::parse_with_markers(lines, |c| {
+        let dir = match c {
+            '^' => Dir4::Up,
+            '>' => Dir4::Right,
+            'v' => Dir4::Down,
+            '<' => Dir4::Left,
+            '.' => return Some((Cell::Unvisited, None)),
+            'X' => return Some((Cell::Visited(Default::default()), None)),
+            '#' => return Some((Cell::Crate, None)),
+            'O' => return Some((Cell::Obstruction, None)),
+            _ => return None,
+        };
+        Some((Cell::Unvisited, Some(dir)))
+    })?;
+    if let Some(&((x, y), _)) = guards.get(1) {
+        return Err(AocError::new("Can't handle multiple guards!").at(y, &lines[y]).at_column(x));
+    }
+    let &((x, y), dir) = guards.first().ok_or("No guard found!")?;
+    Ok(Map {
+        cells,
+        guard: Guard {
+            x: x as isize,
+            y: y as isize,
+            dir,
+        },
+    })
+}
+
+#[derive(Clone)]
+pub(crate) struct Guard {
+    pub(crate) dir: Dir4,
+    pub(crate) x: isize,
+    pub(crate) y: isize,
+}
+
+impl Guard {
+    pub fn next_action(&self) -> (isize, isize) {
+        let offset = self.dir.offset();
+        (offset.x, offset.y)
+    }
+
+    pub fn turn(&mut self) {
+        self.dir = self.dir.turn_right();
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) enum Cell {
+    Unvisited,
+    Visited(VisitHistory),
+    Crate,
+    Obstruction,
+}
+
+// If there is something directly in front of you, turn right 90 degrees.
+// Otherwise, take a step forward.
+
+#[derive(Clone, PartialEq, Debug)]
+#[derive(Default)]
+pub(crate) struct VisitHistory {
+    up: bool,
+    right: bool,
+    down: bool,
+    left: bool,
+}
+
+
+impl VisitHistory {
+    pub fn add(&mut self, dir: &Dir4) {
+        match dir {
+            Dir4::Up => self.up = true,
+            Dir4::Right => self.right = true,
+            Dir4::Down => self.down = true,
+            Dir4::Left => self.left = true,
+        }
+    }
+
+    pub fn has(&self, dir: &Dir4) -> bool {
+        match dir {
+            Dir4::Up => self.up,
+            Dir4::Right => self.right,
+            Dir4::Down => self.down,
+            Dir4::Left => self.left,
+        }
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Cell::Unvisited => write!(f, "."),
+            Cell::Visited(_) => write!(f, "X"),
+            Cell::Crate => write!(f, "#"),
+            Cell::Obstruction => write!(f, "O"),
+        }
+    }
+}
+
+impl Cell {
+
+    pub fn same(&self, b: &Cell) -> bool {
+        matches!((self, b), (Cell::Visited(_), Cell::Visited(_)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_visited_map, find_obstacle_locations, parse_map, print_map, render, run_map, Count, Day06};
+    use crate::util::parsing;
+    use crate::Solution;
+
+    #[test]
+    fn simple() {
+        let lines: Vec<String> = parsing::file_into_vec("files/day_06_example.txt").unwrap();
+        let map = parse_map(&lines).unwrap();
+        print_map(&map);
+        let new_map = run_map(&map).unwrap();
+        println!("---");
+        print_map(&new_map);
+
+        let guess = count_visited_map(&new_map);
+
+        assert_eq!(guess, 41);
+    }
+
+    #[test]
+    fn test_loop() {
+        let lines: Vec<String> = parsing::file_into_vec("files/day_06_example.txt").unwrap();
+        let map = parse_map(&lines).unwrap();
+        println!("Running initial map.");
+        let new_map = run_map(&map).unwrap();
+        println!("Ran map.");
+        let guess = count_visited_map(&new_map);
+        println!("Guard visited {} cells.", guess);
+        let obstacle_locations = find_obstacle_locations(&map, &new_map, guess);
+        assert_eq!(obstacle_locations, 6);
+    }
+
+    #[test]
+    fn test_no_way_out() {
+        let lines = |text: &str| text.split("\n").map(|x| x.to_string()).collect::<Vec<String>>();
+        let looping = lines(".#..\n...#\n#^..\n..#.");
+        let map = Day06::parse(&looping).unwrap();
+        assert!(run_map(&map).is_none());
+        assert_eq!(Day06::part1(&map), Count::NoExit);
+        assert_eq!(Day06::part2(&map), Count::NoExit);
+        // Boxed in, the guard only ever turns.
+        let boxed = Day06::parse(&lines(".#.\n#^#\n.#.")).unwrap();
+        assert!(run_map(&boxed).is_none());
+        assert_eq!(Day06::part1(&boxed), Count::NoExit);
+        assert_eq!(render(&boxed).1, Count::NoExit);
+    }
+
+    #[test]
+    fn test_render() {
+        let lines: Vec<String> = parsing::file_into_vec("files/day_06_example.txt").unwrap();
+        let (canvas, obstacles) = render(&parse_map(&lines).unwrap());
+        assert_eq!(obstacles, Count::Of(6));
+        assert_eq!(canvas.get(4, 6), [250, 210, 60]);
+        assert_eq!(canvas.get(4, 1), [70, 130, 220]);
+        assert_eq!(canvas.get(3, 6), [230, 60, 50]);
+        assert_eq!(canvas.get(4, 0), [150, 150, 150]);
+    }
+}
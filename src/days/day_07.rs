@@ -0,0 +1,504 @@
+use std::fmt::{Display, Formatter};
+use crate::util::bitfutz::get_bit_at;
+use crate::util::parsing::{parse_row, scan};
+use crate::{AocError, Solution};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Equation>;
+    type Answer = i64;
+
+    const DAY: u8 = 7;
+    const PART_1: &'static str = "The total calibration result is";
+    const PART_2: &'static str = "The total revised calibration result is";
+
+    fn parse(lines: &Vec<String>) -> Result<Self::Input, AocError> {
+        let mut equations = Vec::with_capacity(lines.len());
+        for (idx, line) in lines.iter().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (left, right) = scan!(line, "{}: {}", i64, String).map_err(|e| e.at_line(idx))?;
+            // Point into the whole line rather than the part after the colon.
+            let offset = line.trim_end().chars().count() - right.chars().count();
+            let right = parse_row::<i64>(idx, &right, None).map_err(|e| {
+                let column = e.column.map_or(0, |column| column - 1 + offset);
+                e.with_snippet(line).at_column(column)
+            })?;
+            if right.is_empty() {
+                return Err(AocError::new("An equation needs at least one number after the colon.").at(idx, line));
+            }
+            if left < 0 || right.iter().any(|&n| n < 0) {
+                return Err(AocError::new("Calibration numbers can't be negative.").at(idx, line));
+            }
+            equations.push(Equation {
+                left,
+                right,
+            });
+        }
+        // Both parts add up left sides, so make sure that can't overflow.
+        equations.iter().try_fold(0i64, |total, eq| total.checked_add(eq.left))
+            .ok_or("The calibration results add up to more than fits in an i64.")?;
+        Ok(equations)
+    }
+
+    fn part1(equations: &Self::Input) -> Self::Answer {
+        equations.iter().map(|eq| {
+            let solvable = eq.solvable_2_rec();
+            if solvable {
+                eq.left
+            } else {
+                0
+            }
+        }).sum()
+    }
+
+    fn part2(equations: &Self::Input) -> Self::Answer {
+        equations.iter().map(|eq| {
+            let solvable = eq.solvable_3_rec();
+            if solvable {
+                eq.left
+            } else {
+                0
+            }
+        }).sum()
+    }
+}
+
+#[derive(Debug)]
+pub struct Equation {
+    left: i64,
+    right: Vec<i64>,
+}
+
+impl Display for Equation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} = ", self.left)?;
+        let len = self.right.len();
+        for (idx, n) in self.right.iter().enumerate() {
+            write!(f, "{}", n)?;
+            if idx != len - 1 {
+                write!(f, " ? ")?;
+            }
+        }
+        write!(f, " :: {}", self.possible_solutions())?;
+        Ok(())
+    }
+}
+
+impl Equation {
+    pub fn possible_solutions(&self) -> u32 {
+        2u32.pow(self.n_operators())
+    }
+
+    pub fn possible_3_solutions(&self) -> u32 {
+        3u32.pow(self.n_operators())
+    }
+
+    pub fn n_operators(&self) -> u32 {
+        (self.right.len() - 1) as u32
+    }
+
+    pub fn solvable(&self) -> bool {
+        self.solve().is_some()
+    }
+
+    pub fn solve(&self) -> Option<Vec<Operator>> {
+        let mut operator_set: Vec<Operator> = Vec::with_capacity(self.n_operators() as usize);
+        for _ in 0..self.n_operators() {
+            operator_set.push(Operator::Plus)
+        }
+        for i in 0..self.possible_solutions() {
+            for j in 0u8..(self.n_operators() as u8) {
+                operator_set[j as usize] = Operator::from(get_bit_at(i, j));
+            }
+            let mut acc = Some(self.right[0]);
+            for j in 1..self.right.len() {
+                let b = self.right[j];
+                let operator = &operator_set[j - 1];
+                acc = acc.and_then(|acc| operator.operate(acc, b));
+
+            }
+            if acc == Some(self.left) {
+                return Some(operator_set)
+            }
+        }
+        None
+    }
+
+    pub fn solvable_3(&self) -> bool {
+        self.solve_3().is_some()
+    }
+
+    pub fn solve_3(&self) -> Option<Vec<Operator3>> {
+        let mut operator_set: Vec<Operator3> = vec![Operator3::Plus; self.n_operators() as usize];
+        for _ in 0..self.possible_3_solutions() {
+            let mut acc = Some(self.right[0]);
+            'a: for j in 1..self.right.len() {
+                let b = self.right[j];
+                let operator = &operator_set[j - 1];
+                acc = acc.and_then(|acc| operator.operate(acc, b));
+                if acc.is_none_or(|acc| acc > self.left) {
+                    break 'a;
+                }
+            }
+            if acc == Some(self.left) {
+                return Some(operator_set)
+            }
+            next_base_3(&mut operator_set); }
+        None
+    }
+
+    /// Starts from the first number, since an operator applied to it would let `0 * first` drop it.
+    pub fn solvable_2_rec(&self) -> bool {
+        self.solvable_2_rec_(self.right[0], 1)
+    }
+
+    fn solvable_2_rec_(&self, acc: i64, idx: usize) -> bool {
+        if idx >= self.right.len() {
+            return acc == self.left
+        }
+        let lhs = acc;
+        let rhs = self.right[idx];
+        self.solvable_rec_2_inner(lhs, rhs, Operator3::Plus, idx)
+        || self.solvable_rec_2_inner(lhs, rhs, Operator3::Times, idx)
+    }
+
+    pub fn solvable_3_rec(&self) -> bool {
+        self.solvable_3_rec_(self.right[0], 1)
+    }
+
+    fn solvable_3_rec_(&self, acc: i64, idx: usize) -> bool {
+        if idx >= self.right.len() {
+            return acc == self.left
+        }
+        let lhs = acc;
+        let rhs = self.right[idx];
+        self.solvable_rec_3_inner(lhs, rhs, Operator3::Plus, idx)
+        || self.solvable_rec_3_inner(lhs, rhs, Operator3::Times, idx)
+        || self.solvable_rec_3_inner(lhs, rhs, Operator3::Cat, idx)
+    }
+
+    fn solvable_rec_3_inner(&self, lhs: i64, rhs: i64, op: Operator3, idx: usize) -> bool {
+        match op.operate(lhs, rhs) {
+            Some(acc) if acc <= self.left => self.solvable_3_rec_(acc, idx + 1),
+            _ => false,
+        }
+    }
+
+    fn solvable_rec_2_inner(&self, lhs: i64, rhs: i64, op: Operator3, idx: usize) -> bool {
+        match op.operate(lhs, rhs) {
+            Some(acc) if acc <= self.left => self.solvable_2_rec_(acc, idx + 1),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Operator {
+    Plus,
+    Times,
+}
+
+impl Operator {
+    pub fn from(bit: bool) -> Self {
+        match bit {
+            false => Self::Plus,
+            true => Self::Times,
+        }
+    }
+
+    /// `None` if the result overflows.
+    pub fn operate(&self, a: i64, b: i64) -> Option<i64> {
+        match self {
+            Operator::Plus => a.checked_add(b),
+            Operator::Times => a.checked_mul(b),
+        }
+    }
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operator::Plus => write!(f, "+"),
+            Operator::Times => write!(f, "x"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Operator3 {
+    Plus,
+    Times,
+    Cat,
+}
+
+impl Operator3 {
+    /// `None` if the result overflows.
+    pub fn operate(&self, a: i64, b: i64) -> Option<i64> {
+        match self {
+            Self::Plus => a.checked_add(b),
+            Self::Times => a.checked_mul(b),
+            Self::Cat => {
+                let cat = format!("{}{}", a, b);
+                cat.parse::<i64>().ok()
+            },
+        }
+    }
+
+    pub fn next(&self) -> (Self, bool) {
+        match self {
+            Operator3::Plus => (Self::Times, false),
+            Operator3::Times => (Self::Cat, false),
+            Operator3::Cat => (Self::Plus, true),
+        }
+    }
+
+    pub fn double_next(&self) -> (Self, bool) {
+        match self {
+            Operator3::Plus => (Self::Cat, false),
+            Operator3::Times => (Self::Plus, true),
+            Operator3::Cat => (Self::Times, true),
+        }
+    }
+}
+
+impl Display for Operator3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Plus => write!(f, "+"),
+            Self::Times => write!(f, "x"),
+            Self::Cat => write!(f, "||"),
+        }
+    }
+}
+
+pub fn next_base_3(num: &mut Vec<Operator3>) {
+    let mut carry = true;
+    for digit in num.iter_mut() {
+        let n;
+        (n, carry) = match carry {
+            true => digit.next(),
+            false => (digit.clone(), false),
+        };
+        *digit = n;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use crate::Solution;
+    use super::{next_base_3, Day07, Equation, Operator3};
+
+    #[test]
+    fn basic() {
+        let test_input = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+        let expected_solvable = [
+            true,
+            true,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+            true
+        ];
+
+        let lines: Vec<String> = test_input.split("\n").map(|x| x.to_string()).collect();
+        let equations = Day07::parse(&lines).unwrap();
+        let total: i64 = equations.iter().enumerate().map(|(idx, eq)|{
+            let _solution = eq.solve();
+            let solvable = eq.solvable();
+            assert_eq!(solvable, expected_solvable[idx]);
+            if solvable {
+                eq.left
+            } else {
+                0
+            }
+        }).sum();
+        assert_eq!(total, 3749)
+    }
+
+    #[test]
+    fn test_solve_3() {
+        let test_input = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+        let expected_solvable = [
+            true,
+            true,
+            false,
+            true,
+            true,
+            false,
+            true,
+            false,
+            true
+        ];
+
+        let lines: Vec<String> = test_input.split("\n").map(|x| x.to_string()).collect();
+        let equations = Day07::parse(&lines).unwrap();
+        let total: i64 = equations.iter().enumerate().map(|(idx, eq)|{
+            let solution = eq.solve_3();
+            println!("{} :: {:?}", eq, solution);
+            let solvable = solution.is_some();
+            assert_eq!(solvable, expected_solvable[idx]);
+            if solvable {
+                eq.left
+            } else {
+                0
+            }
+        }).sum();
+        assert_eq!(total, 11387)
+    }
+
+    #[test]
+    fn test_solve_3_recursive() {
+        let test_input = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+        let expected_solvable = [
+            true,
+            true,
+            false,
+            true,
+            true,
+            false,
+            true,
+            false,
+            true
+        ];
+
+        let lines: Vec<String> = test_input.split("\n").map(|x| x.to_string()).collect();
+        let equations = Day07::parse(&lines).unwrap();
+        let total: i64 = equations.iter().enumerate().map(|(idx, eq)|{
+            let solvable = eq.solvable_3_rec();
+            assert_eq!(solvable, expected_solvable[idx]);
+            if solvable {
+                eq.left
+            } else {
+                0
+            }
+        }).sum();
+        assert_eq!(total, 11387)
+    }
+
+    #[test]
+    fn test_solve_2_recursive() {
+        let test_input = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+        let expected_solvable = [
+            true,
+            true,
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+            true
+        ];
+
+        let lines: Vec<String> = test_input.split("\n").map(|x| x.to_string()).collect();
+        let equations = Day07::parse(&lines).unwrap();
+        let total: i64 = equations.iter().enumerate().map(|(idx, eq)|{
+            let solvable = eq.solvable_2_rec();
+            assert_eq!(solvable, expected_solvable[idx]);
+            if solvable {
+                eq.left
+            } else {
+                0
+            }
+        }).sum();
+        assert_eq!(total, 3749)
+    }
+
+    #[test]
+    fn test_triple_next() {
+        let mut a = vec![Operator3::Plus, Operator3::Plus, Operator3::Plus];
+        println!("{:?}", a);
+        next_base_3(&mut a);
+        println!("{:?}", a);
+        next_base_3(&mut a);
+        println!("{:?}", a);
+        next_base_3(&mut a);
+        println!("{:?}", a);
+        next_base_3(&mut a);
+        println!("{:?}", a);
+        next_base_3(&mut a);
+        println!("{:?}", a);
+        next_base_3(&mut a);
+        println!("{:?}", a);
+        next_base_3(&mut a);
+        println!("{:?}", a);
+        next_base_3(&mut a);
+        println!("{:?}", a);
+        next_base_3(&mut a);
+        println!("{:?}", a);
+    }
+
+    /// Positive numbers as in the puzzle. Half of the left sides are built from the numbers with random operators,
+    /// so plenty of the equations are solvable.
+    fn equations(operators: usize) -> impl Strategy<Value = Equation> {
+        prop::collection::vec(1i64..100, 1..7).prop_flat_map(move |right| {
+            let n = right.len();
+            let built = prop::collection::vec(0..operators, n - 1).prop_map({
+                let right = right.clone();
+                move |ops| {
+                    let ops = ops.iter().map(|&op| [Operator3::Plus, Operator3::Times, Operator3::Cat][op].clone());
+                    right[1..].iter().zip(ops).fold(right[0], |acc, (&b, op)| op.operate(acc, b).unwrap())
+                }
+            });
+            let left = prop_oneof![built, 1i64..10_000];
+            (left, Just(right))
+        }).prop_map(|(left, right)| Equation { left, right })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(5000))]
+
+        #[test]
+        fn solve_agrees_with_solvable_2_rec(equation in equations(2)) {
+            prop_assert_eq!(equation.solve().is_some(), equation.solvable_2_rec(), "{}", equation);
+        }
+
+        #[test]
+        fn solve_3_agrees_with_solvable_3_rec(equation in equations(3)) {
+            prop_assert_eq!(equation.solve_3().is_some(), equation.solvable_3_rec(), "{}", equation);
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use std::cmp::max;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use crate::render::{palette, Canvas, BACKGROUND};
+use crate::util::geom::Point;
+use crate::util::grid::Grid;
+use crate::{AocError, Solution};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = (Vec<Node>, (isize, isize));
+    type Answer = usize;
+
+    const DAY: u8 = 8;
+    const PART_1: &'static str = "The number of antinodes is";
+    const PART_2: &'static str = "The number of resonant antinodes is";
+
+    fn parse(lines: &Vec<String>) -> Result<Self::Input, AocError> {
+        parse_nodes(lines)
+    }
+
+    fn part1((nodes, (width, height)): &Self::Input) -> Self::Answer {
+        find_antinodes(nodes, *width, *height).len()
+    }
+
+    fn part2((nodes, (width, height)): &Self::Input) -> Self::Answer {
+        find_resonant_antinodes(nodes, *width, *height).len()
+    }
+}
+
+pub fn parse_nodes(lines: &Vec<String>) -> Result<(Vec<Node>, (isize, isize)), AocError> {
+    // Only the antennas matter, so the grid itself holds nothing.
+    let (grid, antennas) = Grid::parse_with_markers(lines, |c| Some(((), (c != '.').then_some(c))))?;
+    let nodes = antennas.into_iter().map(|((x, y), c)| Node {
+        x: x as isize,
+        y: y as isize,
+        freq: c.to_string(),
+    }).collect();
+    Ok((nodes, (grid.width() as isize, grid.height() as isize)))
+}
+
+fn partition_nodes(nodes: &Vec<Node>) -> HashMap<String, Vec<&Node>> {
+    let mut node_partitions = HashMap::new();
+    for node in nodes {
+        let freq = node.freq.clone();
+        if !node_partitions.contains_key(&freq) {
+            node_partitions.insert(freq.clone(), Vec::new());
+        }
+        let partition = node_partitions.get_mut(&freq).unwrap();
+        partition.push(node);
+    }
+
+    node_partitions
+}
+
+pub fn find_antinodes(nodes: &Vec<Node>, width: isize, height: isize) -> HashSet<Antinode> {
+    let mut antinodes = HashSet::new();
+    let node_groups = partition_nodes(nodes);
+    for (_, group) in node_groups {
+        let len = group.len();
+        for i in 0..len {
+            for j in i..len {
+                if i == j {
+                    continue;
+                }
+                let a = group[i];
+                let b = group[j];
+                let dir = determine_dir(a, b);
+                let diff_x = (a.x - b.x).abs();
+                let diff_y = (a.y - b.y).abs();
+
+                let aa = Antinode {
+                    x: a.x + (diff_x) * dir.x,
+                    y: a.y + (diff_y) * dir.y,
+                };
+                let ab = Antinode {
+                    x: b.x + -((diff_x) * dir.x),
+                    y: b.y + -((diff_y) * dir.y),
+                };
+                if aa.within_bounds(width, height) {
+                    antinodes.insert(aa);
+                }
+                if ab.within_bounds(width, height) {
+                    antinodes.insert(ab);
+                }
+            }
+        }
+    }
+
+    antinodes
+}
+
+pub fn find_resonant_antinodes(nodes: &Vec<Node>, width: isize, height: isize) -> HashSet<Antinode> {
+    let mut antinodes = HashSet::new();
+    let node_groups = partition_nodes(nodes);
+    for (_, group) in node_groups {
+        let len = group.len();
+        for i in 0..len {
+            for j in i..len {
+                if i == j {
+                    continue;
+                }
+                let a = group[i];
+                let b = group[j];
+                let dir = determine_dir(a, b);
+                let diff_x = (a.x - b.x).abs();
+                let diff_y = (a.y - b.y).abs();
+
+                // Antennas in the same row or column have no step along the other axis.
+                let h_times = width.checked_div(diff_x).unwrap_or(0) + 1;
+                let v_times = height.checked_div(diff_y).unwrap_or(0) + 1;
+                let n = max(h_times, v_times);
+                for i in -n..=n {
+                    let an = Antinode {
+                        x: a.x + (diff_x) * dir.x * i,
+                        y: a.y + (diff_y) * dir.y * i,
+                    };
+                    if an.within_bounds(width, height) {
+                        antinodes.insert(an);
+                    }
+                }
+            }
+        }
+    }
+
+    antinodes
+}
+
+
+fn within_bounds(x: isize, y: isize, width: isize, height: isize) -> bool {
+    x >= 0 && y >= 0 && x < width && y < height
+}
+
+/// Which way `a` lies from `b`, one step along each axis at most.
+fn determine_dir(a: &Node, b: &Node) -> Point<isize> {
+    Point::new((a.x - b.x).signum(), (a.y - b.y).signum())
+}
+
+/// Every resonant antinode dimly, the ones from part 1 brightly, and the antennas on top in a color per frequency.
+pub fn render((nodes, (width, height)): &(Vec<Node>, (isize, isize))) -> Canvas {
+    let mut canvas = Canvas::new(*width as usize, *height as usize, BACKGROUND);
+    for antinode in find_resonant_antinodes(nodes, *width, *height) {
+        canvas.set(antinode.x as usize, antinode.y as usize, [70, 70, 100]);
+    }
+    for antinode in find_antinodes(nodes, *width, *height) {
+        canvas.set(antinode.x as usize, antinode.y as usize, [230, 230, 240]);
+    }
+    let mut frequencies: Vec<&String> = nodes.iter().map(|node| &node.freq).collect();
+    frequencies.sort();
+    frequencies.dedup();
+    for node in nodes {
+        let idx = frequencies.binary_search(&&node.freq).unwrap();
+        canvas.set(node.x as usize, node.y as usize, palette(idx));
+    }
+    canvas
+}
+
+#[derive(Clone)]
+pub struct Node {
+    x: isize,
+    y: isize,
+    freq: String,
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({}, {})", self.freq, self.x, self.y)
+    }
+}
+
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct Antinode {
+    x: isize,
+    y: isize,
+}
+
+impl Display for Antinode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Antinode {
+    pub fn within_bounds(&self, width: isize, height: isize) -> bool {
+        within_bounds(self.x, self.y, width, height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_antinodes, find_resonant_antinodes, parse_nodes, render};
+    use crate::util::parsing;
+
+    #[test]
+    fn basic() {
+        let test_input = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+        let lines: Vec<String> = test_input.split("\n").map(|x| x.to_string()).collect();
+        let (nodes, (width, height)) = parse_nodes(&lines).unwrap();
+
+        let antinodes = find_antinodes(&nodes, width, height);
+        println!("N: {}", antinodes.len());
+        assert_eq!(antinodes.len(), 14);
+
+        let resonant_antinodes = find_resonant_antinodes(&nodes, width, height);
+        println!("N: {}", resonant_antinodes.len());
+        assert_eq!(resonant_antinodes.len(), 34);
+    }
+
+    #[test]
+    fn test_render() {
+        let lines = parsing::file_into_vec("files/day_08_example.txt").unwrap();
+        let canvas = render(&parse_nodes(&lines).unwrap());
+        assert_eq!(canvas.get(0, 0), [70, 70, 100]);
+        assert_eq!(canvas.get(6, 0), [230, 230, 240]);
+        assert_ne!(canvas.get(8, 1), canvas.get(6, 5));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,242 @@
+use std::io;
+use crate::util::log::trace;
+use crate::{AocError, Solution};
+
+type Num = u32;
+
+const ASCII_ZERO: u8 = 48;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Num>;
+    type Answer = u64;
+
+    const DAY: u8 = 9;
+    const PART_1: &'static str = "The checksum of the fragmented disk is";
+    const PART_2: &'static str = "The checksum of the sorted but unfragmented disk is";
+
+    fn parse(lines: &Vec<String>) -> Result<Self::Input, AocError> {
+        let bytes = lines.iter().take(1).flat_map(|line| line.bytes()).map(Ok);
+        parse_bytes(bytes)
+    }
+
+    fn part1(disk: &Self::Input) -> Self::Answer {
+        solve_part_1(disk.clone())
+    }
+
+    fn part2(disk: &Self::Input) -> Self::Answer {
+        solve_part_2(disk.clone())
+    }
+}
+
+/// Reads the disk map up to the first byte below '0', which is the end of the line.
+pub fn parse_bytes<I: Iterator<Item = io::Result<u8>>>(bytes: I) -> Result<Vec<Num>, AocError> {
+    let mut disk: Vec<Num> = Vec::new();
+    let mut file_id: Num = 0;
+    let mut is_file = true;
+    'exit: for (column, byte) in bytes.enumerate() {
+        let byte = byte?;
+        if byte < ASCII_ZERO {
+            break 'exit;
+        }
+        if byte > b'9' {
+            return Err(AocError::new(format!("Expected a digit, found '{}'.", byte as char)).at_line(0).at_column(column));
+        }
+        let size = byte - ASCII_ZERO;
+        let n = match is_file {
+            true => {
+                is_file = false;
+                let temp = file_id;
+                // Num::MAX marks free space, so it can't be a file id.
+                if temp == Num::MAX {
+                    return Err(AocError::new(format!("More than {} files.", Num::MAX)).at_line(0).at_column(column));
+                }
+                file_id += 1;
+                temp
+            }
+            false => {
+                is_file = true;
+                Num::MAX
+            },
+        };
+        for _ in 0..size {
+            disk.push(n);
+        }
+    }
+    Ok(disk)
+}
+
+pub fn solve_part_1(mut disk: Vec<Num>) -> u64 {
+    if disk.is_empty() {
+        return 0;
+    }
+    let mut front: usize = 0;
+    let mut back: usize = disk.len() - 1;
+    let limit: usize = disk.len();
+    let mut loops: usize = 0;
+    'quit: while front < back && loops < limit {
+        loops += 1;
+
+        while disk[front] != Num::MAX {
+            front += 1;
+            if front == back {
+                break 'quit;
+            }
+        }
+        while disk[back] == Num::MAX {
+            back -= 1;
+            if front == back {
+                break 'quit;
+            }
+        }
+        disk[front] = disk[back];
+        disk[back] = Num::MAX;
+        trace!("{}", debug_string(&disk));
+    }
+
+    checksum(&disk)
+}
+
+pub fn solve_part_2(mut disk: Vec<Num>) -> u64 {
+    trace!("{:?}", disk);
+    if disk.is_empty() {
+        return 0;
+    }
+    let mut back: usize = disk.len() - 1;
+    'quit: while back > 0 {
+        while disk[back] == Num::MAX {
+            back -= 1;
+            if back == 0 {
+                trace!("Only free space left behind the front.");
+                break 'quit;
+            }
+        }
+        let current_id = disk[back];
+        let mut back_start = back;
+        'found: while disk[back_start] == current_id {
+            back_start -= 1;
+            if back_start == 0 {
+                trace!("The last file reaches the front.");
+                break 'quit;
+            }
+            if disk[back_start] != current_id {
+                back_start += 1;
+                break 'found;
+            }
+        }
+        let range_to_move = &disk[back_start..=back];
+        let required_length = range_to_move.len();
+        trace!("[{}] ({})", range_to_move.iter().map(|id| id.to_string()).collect::<String>(), required_length);
+
+        let mut front = 0;
+        let mut front_end = 0;
+        'outer: while front + required_length < back_start {
+            while disk[front] != Num::MAX && front + 1 < back_start {
+                front += 1;
+                if disk[front] == Num::MAX {
+                    front_end = front;
+                    while disk[front_end] == Num::MAX {
+                        front_end += 1;
+                    }
+                    if front_end - front >= required_length {
+                        break 'outer;
+                    }
+                }
+            }
+            front += 1;
+        }
+
+        trace!("{} -> {}", front, front_end);
+        if front_end >= front && front_end - front >= required_length {
+            trace!("SWAP");
+            for i in 0..required_length {
+                disk[front + i] = disk[back_start + i];
+                disk[back_start + i] = Num::MAX;
+            }
+        }
+
+        trace!("{}", debug_string(&disk));
+        back -= required_length;
+    }
+
+    checksum(&disk)
+}
+
+pub fn checksum(disk: &Vec<Num>) -> u64 {
+    let mut sum: u64 = 0;
+    for (idx, id) in disk.iter().enumerate() {
+        let id = *id;
+        if id != Num::MAX {
+            sum += (id as u64) * (idx as u64)
+        }
+    }
+    sum
+}
+
+pub fn debug_print(disk: &Vec<Num>) {
+    for byte in disk {
+        if *byte == Num::MAX {
+            print!(".");
+        } else {
+            print!("{}", byte)
+        }
+    }
+    println!()
+}
+
+pub fn debug_string(disk: &Vec<Num>) -> String {
+    let mut result = String::with_capacity(disk.len());
+    for byte in disk {
+        if *byte == Num::MAX {
+            result.push('.');
+        } else {
+            result.push((*byte as u8 + 48) as char)
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_bytes, solve_part_1, solve_part_2};
+    use crate::util::bytewise::ByteIterator;
+
+    #[test]
+    fn tiny_input() {
+        let bytes = ByteIterator::new("files/day_09_tiny.txt").unwrap();
+        let disk = parse_bytes(bytes).unwrap();
+        let disk_backup = disk.clone();
+        let _part_1 = solve_part_1(disk);
+        println!();
+        let _part_2 = solve_part_2(disk_backup);
+        let _steps = [
+            "0..111....22222",
+            "02.111....2222.",
+            "022111....222..",
+            "0221112...22...",
+            "02211122..2....",
+            "022111222......"
+        ];
+    }
+
+    #[test]
+    fn small_input() {
+        // 2333133121414131402
+        let bytes = ByteIterator::new("files/day_09_small.txt").unwrap();
+        let disk = parse_bytes(bytes).unwrap();
+        let disk_backup = disk.clone();
+        let part_1 = solve_part_1(disk);
+        assert_eq!(part_1, 1928);
+        let _part_2 = solve_part_2(disk_backup);
+    }
+
+    #[test]
+    fn many_files() {
+        // More files than a u16 id could number.
+        let line = "11".repeat(70_000);
+        let disk = parse_bytes(line.bytes().map(Ok)).unwrap();
+        assert_eq!(disk[disk.len() - 2], 69_999);
+    }
+
+}
@@ -0,0 +1,234 @@
+use std::collections::HashSet;
+use crate::render::{mix, Canvas, BACKGROUND};
+use crate::util::grid::Grid;
+use crate::{AocError, Solution};
+
+
+type Num = u8;
+
+/// '.' in the examples: higher than any trail, so nothing ever steps onto it.
+const IMPASSABLE: Num = Num::MAX;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<Num>;
+    type Answer = usize;
+
+    const DAY: u8 = 10;
+    const PART_1: &'static str = "The total scores of all trailheads are";
+    const PART_2: &'static str = "The total ratings of all trailheads are";
+
+    fn parse(lines: &Vec<String>) -> Result<Self::Input, AocError> {
+        parse_map(lines)
+    }
+
+    fn part1(map: &Self::Input) -> Self::Answer {
+        score_and_rate_trails(map).0
+    }
+
+    fn part2(map: &Self::Input) -> Self::Answer {
+        score_and_rate_trails(map).1
+    }
+}
+
+fn parse_map(lines: &Vec<String>) -> Result<Grid<Num>, AocError> {
+    Grid::parse(lines, |c| match c {
+        '0'..='9' => Some((c as u8 - b'0') as Num),
+        '.' => Some(IMPASSABLE),
+        _ => None,
+    })
+}
+
+pub fn score_and_rate_trails(map: &Grid<Num>) -> (usize, usize) {
+    let mut scores: usize = 0;
+    let mut ratings: usize = 0;
+    for y in 0..map.height() {
+        for x in 0..map.width() {
+            if map[(x, y)] == 0 {
+                let (score, rating) = score_and_rate_trail(map, x, y);
+                scores += score;
+                ratings += rating;
+            }
+        }
+    }
+    (scores, ratings)
+}
+
+/// Heights in grey, lighter going up, and trailheads from dark to bright green by score.
+pub fn render(map: &Grid<Num>) -> Canvas {
+    let mut canvas = Canvas::new(map.width(), map.height(), BACKGROUND);
+    let mut trailheads = Vec::new();
+    for y in 0..map.height() {
+        for x in 0..map.width() {
+            match map[(x, y)] {
+                IMPASSABLE => {},
+                0 => trailheads.push((x, y, score_and_rate_trail(map, x, y).0)),
+                height => canvas.set(x, y, mix([40, 40, 48], [200, 200, 210], height as f64 / 9.0)),
+            }
+        }
+    }
+    let best = trailheads.iter().map(|&(_, _, score)| score).max().unwrap_or(0).max(1);
+    for (x, y, score) in trailheads {
+        canvas.set(x, y, mix([20, 70, 30], [60, 240, 90], score as f64 / best as f64));
+    }
+    canvas
+}
+
+pub fn score_and_rate_trail(map: &Grid<Num>, x: usize, y: usize) -> (usize, usize) {
+    let mut rating = Vec::new();
+    rate_trail_inner(map, x, y, &mut rating);
+    let mut score = HashSet::with_capacity(rating.len());
+    for (x_, y_) in &rating {
+        score.insert((*x_, *y_));
+    }
+
+    (score.len(), rating.len())
+}
+
+pub fn rate_trail_inner(map: &Grid<Num>, x: usize, y: usize, vec: &mut Vec<(usize, usize)>) {
+    let current_n = map[(x, y)];
+    if current_n == 9 {
+        vec.push((x, y));
+        return;
+    }
+    let dirs = directions(map, x, y);
+    for (next_x, next_y) in dirs {
+        rate_trail_inner(map, next_x, next_y, vec)
+    }
+}
+
+
+
+pub fn directions(map: &Grid<Num>, x: usize, y: usize) -> Vec<(usize, usize)> {
+    let positions: Vec<(usize, usize)> = map.neighbors4(x, y)
+        .filter(|&next| map[next] == map[(x, y)] + 1)
+        .collect();
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{directions, parse_map, render, score_and_rate_trail, score_and_rate_trails, Num};
+    use crate::util::grid::Grid;
+
+    #[test]
+    fn test_score_paths() {
+        let test_input = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+        let expected_scores: Vec<(usize, usize, usize, usize)> = vec![
+            (5, 20, 0, 2),
+            (6, 24, 0, 4),
+            (5, 10, 2, 4),
+            (3, 4, 4, 6),
+            (1, 1, 5, 2),
+            (3, 4, 5, 5),
+            (5, 5, 6, 0),
+            (3, 8, 6, 6),
+            (5, 5, 7, 1),
+        ];
+        let lines: Vec<String> = test_input.split("\n").map(|x| x.to_string()).collect();
+        let map = parse_map(&lines).unwrap();
+        let (score, rating) = score_and_rate_trails(&map);
+        let actual_scores: Vec<(usize, usize, usize, usize, usize, usize)> = expected_scores.iter().map(|(expected_score, expected_rating, y, x)| {
+            let (actual_score, actual_rating) = score_and_rate_trail(&map, *x, *y);
+            println!("({}, {}) -> (expected: {}, actual: {})", x, y, *expected_score, actual_score);
+            (*expected_score, *expected_rating, *x, *y, actual_score, actual_rating)
+        }).collect();
+        let expected_score = 36;
+        let expected_rating = 81;
+        println!("Total Score: {} (Expected {})", score, expected_score);
+        for (expected_score, expected_rating, _x, _y, actual_score, actual_rating) in actual_scores {
+            assert_eq!(expected_score, actual_score);
+            assert_eq!(expected_rating, actual_rating);
+        }
+        assert_eq!(score, expected_score);
+        assert_eq!(rating, expected_rating);
+    }
+
+    #[test]
+    fn test_one_path_score() {
+        let test_input = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+        let lines: Vec<String> = test_input.split("\n").map(|x| x.to_string()).collect();
+        let map = parse_map(&lines).unwrap();
+        let (actual_score, _actual_rating) = score_and_rate_trail(&map, 2, 0);
+        assert_eq!(actual_score, 5);
+    }
+
+    #[test]
+    fn test_directions() {
+        let only_right = Grid::<Num>::from_rows(vec![
+            vec![5, 2, 5],
+            vec![0, 0, 1],
+            vec![5, 5, 5],
+        ]).unwrap();
+        let dirs = directions(&only_right, 1, 1);
+        println!("{:?}", dirs);
+        assert_eq!(dirs.len(), 1);
+        assert_eq!(dirs[0], (2usize, 1usize));
+
+        let all_four = Grid::<Num>::from_rows(vec![
+            vec![5, 1, 5],
+            vec![1, 0, 1],
+            vec![5, 1, 5],
+        ]).unwrap();
+        let dirs = directions(&all_four, 1, 1);
+        println!("{:?}", dirs);
+        assert_eq!(dirs.len(), 4);
+
+        let all_eight = Grid::<Num>::from_rows(vec![
+            vec![1, 1, 1],
+            vec![1, 0, 1],
+            vec![1, 1, 1],
+        ]).unwrap();
+        let dirs = directions(&all_eight, 1, 1);
+        println!("{:?}", dirs);
+        assert_eq!(dirs.len(), 4);
+
+        let from_five = Grid::<Num>::from_rows(vec![
+            vec![5, 6, 5],
+            vec![1, 5, 1],
+            vec![5, 6, 5],
+        ]).unwrap();
+        let dirs = directions(&from_five, 1, 1);
+        println!("{:?}", dirs);
+        assert_eq!(dirs.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let lines: Vec<String> = "...0...\n...1...\n...2...\n6543456\n7.....7\n8.....8\n9.....9".split("\n").map(|x| x.to_string()).collect();
+        let map = parse_map(&lines).unwrap();
+        assert_eq!(score_and_rate_trails(&map).0, 2);
+
+        let lines: Vec<String> = "0123\n1x34".split("\n").map(|x| x.to_string()).collect();
+        let error = parse_map(&lines).unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(2)));
+
+        let lines: Vec<String> = "0123\n123".split("\n").map(|x| x.to_string()).collect();
+        assert_eq!(parse_map(&lines).unwrap_err().line, Some(2));
+    }
+
+    #[test]
+    fn test_render() {
+        let lines: Vec<String> = "0123\n.654\n0789".split("\n").map(|x| x.to_string()).collect();
+        let canvas = render(&parse_map(&lines).unwrap());
+        assert_eq!(canvas.get(0, 0), [60, 240, 90]);
+        assert_eq!(canvas.get(0, 2), [20, 70, 30]);
+        assert_eq!(canvas.get(3, 2), [200, 200, 210]);
+        assert_eq!(canvas.get(0, 1), crate::render::BACKGROUND);
+    }
+}
@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+
+use crate::util::parsing::parse_row;
+use crate::{AocError, Solution};
+
+type Num = u64;
+
+/// Every stone below this stays below `Num::MAX` however long it blinks: an odd number of up to 11 digits
+/// grows to at most 15, and that one starts with a 1 or 2, so it only gets to 18 digits before it splits.
+const MAX_STONE: Num = 1_000_000_000_000;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Num>;
+    type Answer = Num;
+
+    const DAY: u8 = 11;
+    const PART_1: &'static str = "After 25 blink(s) the number of stones is";
+    const PART_2: &'static str = "After 75 blink(s) the number of stones is";
+
+    fn parse(lines: &Vec<String>) -> Result<Self::Input, AocError> {
+        let first = lines.first().ok_or("Empty input")?;
+        let stones: Vec<Num> = parse_row(0, first, None)?;
+        if let Some(stone) = stones.iter().find(|&&stone| stone >= MAX_STONE) {
+            return Err(AocError::new(format!("Stone {} is too big to blink at, the limit is {}.", stone, MAX_STONE)).at(0, first));
+        }
+        Ok(stones)
+    }
+
+    fn part1(stones: &Self::Input) -> Self::Answer {
+        blink(stones, 25)
+    }
+
+    fn part2(stones: &Self::Input) -> Self::Answer {
+        blink(stones, 75)
+    }
+}
+
+fn blink(stones: &Vec<Num>, times: usize) -> Num {
+    let mut map = to_hashmap(stones);
+    for _ in 0..times {
+        map = advance_faster(&map);
+    }
+    count_stones(&map)
+}
+
+pub fn advance(stones: &Vec<Num>) -> Vec<Num> {
+    let mut changed = Vec::with_capacity(stones.len() * 2);
+
+    'top: for stone in stones {
+        let stone = *stone;
+        if stone == 0 {
+            changed.push(1);
+            continue 'top;
+        }
+        let stone_str = stone.to_string();
+        let len = stone_str.len();
+        if stone_str.len() % 2 == 0 {
+            let n = len / 2;
+            let first_half = &stone_str[0..n];
+            let second_half = &stone_str[n..];
+            changed.push(first_half.parse::<Num>().unwrap());
+            changed.push(second_half.parse::<Num>().unwrap());
+            continue 'top;
+        }
+        changed.push(stone * 2024);
+    }
+
+    changed
+}
+
+pub fn to_hashmap(stones: &Vec<Num>) -> HashMap<Num, Num> {
+    let mut map: HashMap<Num, Num> = HashMap::new();
+    for stone in stones {
+        map.entry(*stone).and_modify(|value| { *value += 1}).or_insert(1);
+    }
+    map
+}
+
+pub fn advance_faster(stones: &HashMap<Num, Num>) -> HashMap<Num, Num> {
+    let mut new_stones = HashMap::with_capacity(stones.len() * 2);
+
+    let keys = stones.keys();
+    'top: for key in keys {
+        let stone_label_number = *key;
+        let n_stones_like_this = *(stones.get(key).unwrap());
+        if stone_label_number == 0 {
+            new_stones.entry(1)
+                .and_modify(|value| { *value += n_stones_like_this }).or_insert(n_stones_like_this);
+            continue 'top;
+        }
+        let stone_str = stone_label_number.to_string();
+        let len = stone_str.len();
+        if stone_str.len() % 2 == 0 {
+            let n = len / 2;
+            let first_half = stone_str[0..n].parse::<Num>().unwrap();
+            let second_half = stone_str[n..].parse::<Num>().unwrap();
+            new_stones.entry(first_half)
+                .and_modify(|count| *count += n_stones_like_this)
+                .or_insert(n_stones_like_this);
+            new_stones.entry(second_half)
+                .and_modify(|count| *count += n_stones_like_this)
+                .or_insert(n_stones_like_this);
+            continue 'top;
+        }
+        new_stones.entry(stone_label_number * 2024)
+            .and_modify(|count| *count += n_stones_like_this)
+            .or_insert(n_stones_like_this);
+
+    }
+    new_stones
+}
+
+pub fn count_stones(stones: &HashMap<Num, Num>) -> Num {
+    stones.values().copied().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use proptest::prelude::*;
+    use super::{advance, advance_faster, count_stones, to_hashmap, Num};
+    use crate::bench::measure;
+
+    #[test]
+    fn basic() {
+        let step_0: Vec<Num> = vec![125, 17];
+        let step_1: Vec<Num> = vec![253000, 1, 7];
+        let step_2: Vec<Num> = vec![253, 0, 2024, 14168];
+        let step_3: Vec<Num> = vec![512072, 1, 20, 24, 28676032];
+        let step_4: Vec<Num> = vec![512, 72, 2024, 2, 0, 2, 4, 2867, 6032];
+        let step_5: Vec<Num> = vec![1036288, 7, 2, 20, 24, 4048, 1, 4048, 8096, 28, 67, 60, 32];
+        let step_6: Vec<Num> = vec![2097446912, 14168, 4048, 2, 0, 2, 4, 40, 48, 2024, 40, 48, 80, 96, 2, 8, 6, 7, 6, 0, 3, 2];
+
+        println!("{:?}", step_0);
+        let steps = [step_0.clone(), step_1, step_2, step_3, step_4, step_5, step_6];
+        for i in 1..(steps.len()) {
+            let starting_step = &steps[i - 1];
+            let actual = advance(starting_step);
+            let expected_step = &steps[i];
+            println!("{:?}", actual);
+            assert_eq!(actual.len(), expected_step.len());
+            if i == 6 {
+                assert_eq!(22, actual.len());
+            }
+        }
+
+        let mut test_case = step_0;
+        for _i in 1..=25 {
+            test_case = advance(&test_case);
+        }
+        assert_eq!(test_case.len(), 55312);
+    }
+
+    #[test]
+    fn test_to_hashmap() {
+        let step_0: Vec<Num> = vec![125, 17, 17];
+        let mut step_0_expected = HashMap::new();
+        step_0_expected.insert(125,  1);
+        step_0_expected.insert(17,  2);
+        let step_0_actual = to_hashmap(&step_0);
+        assert_eq!(step_0_actual.keys().len(), step_0_expected.keys().len());
+        assert_eq!(step_0_actual.get(&17).unwrap(), step_0_expected.get(&17).unwrap());
+        assert_eq!(step_0_actual.get(&125).unwrap(), step_0_expected.get(&125).unwrap());
+    }
+
+    #[test]
+    fn hashmapped() {
+        let step_0: Vec<Num> = vec![125, 17];
+        let mut map = to_hashmap(&step_0);
+        println!("{:?}", map);
+
+        for i in 1..=25 {
+            map = advance_faster(&map);
+            if i < 5 {
+                println!("{:?}", map);
+            }
+            if i == 1 {
+                assert_eq!(3, count_stones(&map));
+            }
+            if i == 2 {
+                assert_eq!(4, count_stones(&map));
+            }
+            if i == 3 {
+                assert_eq!(5, count_stones(&map));
+            }
+            if i == 4 {
+                assert_eq!(9, count_stones(&map));
+            }
+            if i == 5 {
+                assert_eq!(13, count_stones(&map));
+            }
+            if i == 6 {
+                assert_eq!(22, count_stones(&map));
+            }
+            if i == 25 {
+                println!("{:?}", map);
+                assert_eq!(55312, count_stones(&map));
+            }
+        }
+    }
+
+    /// `cargo test --release -- --ignored --nocapture advance_vs`
+    #[test]
+    #[ignore]
+    fn bench_advance_vs_advance_faster() {
+        let stones: Vec<Num> = vec![125, 17];
+        let slow = measure(3, 20, || {
+            let mut stones = stones.clone();
+            for _ in 0..25 {
+                stones = advance(&stones);
+            }
+            stones.len() as Num
+        });
+        let fast = measure(3, 20, || {
+            let mut map = to_hashmap(&stones);
+            for _ in 0..25 {
+                map = advance_faster(&map);
+            }
+            count_stones(&map)
+        });
+        println!("{:<16} {}", "advance", slow);
+        println!("{:<16} {}", "advance_faster", fast);
+    }
+
+    /// Stones small enough that a few blinks can't overflow, with repeats so the counts matter.
+    fn stones() -> impl Strategy<Value = Vec<Num>> {
+        prop::collection::vec(prop_oneof![0..10 as Num, 0..1_000_000_000 as Num], 0..20)
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(5000))]
+
+        #[test]
+        fn advance_faster_agrees_with_advance(stones in stones(), blinks in 1..5usize) {
+            let mut slow = stones.clone();
+            let mut fast = to_hashmap(&stones);
+            for _ in 0..blinks {
+                slow = advance(&slow);
+                fast = advance_faster(&fast);
+            }
+            prop_assert_eq!(to_hashmap(&slow), fast);
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use crate::render::{palette, Canvas, BACKGROUND};
+use crate::util::grid::Grid;
+use crate::util::log::{debug, trace};
+use crate::{AocError, Solution};
+
+type Num = u64;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+    type Answer = Num;
+
+    const DAY: u8 = 12;
+    const PART_1: &'static str = "Price of all fence is";
+    const PART_2: &'static str = "Price of all fence in bulk is";
+
+    fn parse(lines: &Vec<String>) -> Result<Self::Input, AocError> {
+        parse_input(lines)
+    }
+
+    fn part1(garden: &Self::Input) -> Self::Answer {
+        find_total_price(garden).0
+    }
+
+    fn part2(garden: &Self::Input) -> Self::Answer {
+        find_total_price(garden).1
+    }
+}
+
+/// Plants are capital letters, as in the puzzle.
+fn parse_input(lines: &Vec<String>) -> Result<Grid<char>, AocError> {
+    Grid::parse(lines, |c| c.is_ascii_uppercase().then_some(c))
+}
+
+pub fn debug_print_garden(garden: &Grid<char>) {
+    println!("{}", garden);
+}
+
+/// Each region in its own color.
+pub fn render(garden: &Grid<char>) -> Canvas {
+    let mut canvas = Canvas::new(garden.width(), garden.height(), BACKGROUND);
+    for (idx, plots) in regions(garden).iter().enumerate() {
+        let color = palette(idx);
+        for &(x, y) in plots {
+            canvas.set(x, y, color);
+        }
+    }
+    canvas
+}
+
+/// Every region's plots, flooding out from each plot in reading order that isn't in a region yet.
+pub fn regions(garden: &Grid<char>) -> Vec<Vec<(usize, usize)>> {
+    let mut seen = garden.map(|_| false);
+    let mut regions = Vec::new();
+    for ((x, y), &plant) in garden.iter() {
+        if seen[(x, y)] {
+            continue;
+        }
+        seen[(x, y)] = true;
+        let mut plots = Vec::new();
+        let mut stack = vec![(x, y)];
+        while let Some((x, y)) = stack.pop() {
+            plots.push((x, y));
+            for (nx, ny) in garden.neighbors4(x, y) {
+                if !seen[(nx, ny)] && garden[(nx, ny)] == plant {
+                    seen[(nx, ny)] = true;
+                    stack.push((nx, ny));
+                }
+            }
+        }
+        trace!("Region of {} at ({}, {}): {:?}", plant, x, y, plots);
+        regions.push(plots);
+    }
+    regions
+}
+
+pub fn find_total_price(garden: &Grid<char>) -> (Num, Num) {
+    let mut total = 0;
+    let mut bulk_total = 0;
+    for plots in regions(garden) {
+        let plant = garden[plots[0]];
+        let area = plots.len() as Num;
+        let perimeter: Num = plots.iter()
+            .map(|&(x, y)| 4 - garden.neighbors4(x, y).filter(|&neighbor| garden[neighbor] == plant).count() as Num)
+            .sum();
+        let n_corners: Num = plots.iter().map(|&(x, y)| n_corners(garden, plant, x, y)).sum();
+        debug!("Plant {} has an area of {} and a perimeter of {} for a cost of {}", plant, area, perimeter, area * perimeter);
+        debug!("Plant {} has an area of {} and {} corners for a cost of {}", plant, area, n_corners, area * n_corners);
+        total += area * perimeter;
+        bulk_total += area * n_corners;
+    }
+    (total, bulk_total)
+}
+
+pub fn n_corners(garden: &Grid<char>, kind: char, x: usize, y: usize) -> Num {
+    let same = |dx: isize, dy: isize| {
+        garden.get(x as isize + dx, y as isize + dy) == Some(&kind)
+    };
+
+    let n_0_0: bool = same(-1, -1);
+    let n_0_1: bool = same(0, -1);
+    let n_0_2: bool = same(1, -1);
+
+    let n_1_0: bool = same(-1, 0);
+    let _n_1_1: bool = true;
+    let n_1_2: bool = same(1, 0);
+
+    let n_2_0: bool = same(-1, 1);
+    let n_2_1: bool = same(0, 1);
+    let n_2_2: bool = same(1, 1);
+
+    // n_0_0 n_0_1 n_0_2
+    // n_1_0 n_1_1 n_1_2
+    // n_2_0 n_2_1 n_2_2
+
+    trace!("{}, {}", x, y);
+    trace!("{}{}{}\n{}{}{}\n{}{}{}", n_0_0 as u32, n_0_1 as u32, n_0_2 as u32,
+           n_1_0 as u32, _n_1_1 as u32, n_1_2 as u32, n_2_0 as u32, n_2_1 as u32, n_2_2 as u32);
+    let top_left = !n_0_0 && (n_0_1 == n_1_0);
+    let top_right = !n_0_2 && (n_0_1 == n_1_2);
+    let bottom_right = !n_2_2 && (n_1_2 == n_2_1);
+    let bottom_left = !n_2_0 && (n_1_0 == n_2_1);
+
+    let top_left_special = n_0_0 && (!n_0_1 && !n_1_0);
+    let top_right_special = n_0_2 && (!n_0_1 && !n_1_2);
+    let bottom_right_special = n_2_2 && (!n_1_2 && !n_2_1);
+    let bottom_left_special = n_2_0 && (!n_1_0 && !n_2_1);
+
+    trace!("top_left: {}, top_right: {}, bottom_right: {}, bottom_left: {}\n", top_left, top_right, bottom_right, bottom_left);
+
+    (top_left as Num)
+        + (top_right as Num)
+        + (bottom_right as Num)
+        + (bottom_left as Num)
+        + (top_left_special as Num)
+        + (top_right_special as Num)
+        + (bottom_right_special as Num)
+        + (bottom_left_special as Num)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use super::{find_total_price, parse_input, render};
+    use crate::util::parsing;
+
+    #[test]
+    fn test_one_big_region() -> Result<(), Box<dyn Error>> {
+        // Deep enough to overflow the stack when each plot was a recursive call.
+        let lines = vec!["A".repeat(500); 500];
+        let garden = parse_input(&lines)?;
+        assert_eq!(find_total_price(&garden), (250_000 * 2000, 250_000 * 4));
+        Ok(())
+    }
+
+    #[test]
+    fn test_render() -> Result<(), Box<dyn Error>> {
+        let lines = parsing::file_into_vec("files/day_12_xoxo.txt")?;
+        let canvas = render(&parse_input(&lines)?);
+        // The O plots all join up around the four X plots, each a region of its own.
+        assert_eq!(canvas.get(0, 0), canvas.get(4, 4));
+        assert_ne!(canvas.get(1, 1), canvas.get(3, 1));
+        assert_ne!(canvas.get(1, 1), canvas.get(0, 0));
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+use std::fmt::{Display, Formatter};
+use crate::util::parsing::{records, scan, RecordSize};
+use crate::{AocError, Solution};
+
+type Num = i64;
+/// Room for the products in Cramer's rule, which overflow `Num` for prizes far enough away.
+type Wide = i128;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
+    type Answer = Wide;
+
+    const DAY: u8 = 13;
+    const PART_1: &'static str = "Total cost";
+    const PART_2: &'static str = "Total _true_ cost";
+
+    fn parse(lines: &Vec<String>) -> Result<Self::Input, AocError> {
+        parse_input(lines)
+    }
+
+    fn part1(machines: &Self::Input) -> Self::Answer {
+        machines.iter().filter_map(|m| m.solve()).sum()
+    }
+
+    fn part2(machines: &Self::Input) -> Self::Answer {
+        machines.iter().filter_map(|m| m.solve_sad()).sum()
+    }
+}
+
+fn parse_input(input: &Vec<String>) -> Result<Vec<Machine>, AocError> {
+    records(input, RecordSize::Fixed(3)).map(|record| {
+        let record = record?;
+        let machine = Machine {
+            a: scan!(record.lines[0], "Button A: X+{}, Y+{}", Num, Num).map_err(|e| e.at_line(record.index(0)))?,
+            b: scan!(record.lines[1], "Button B: X+{}, Y+{}", Num, Num).map_err(|e| e.at_line(record.index(1)))?,
+            prize: scan!(record.lines[2], "Prize: X={}, Y={}", Num, Num).map_err(|e| e.at_line(record.index(2)))?,
+        };
+        if [machine.a.0, machine.a.1, machine.b.0, machine.b.1, machine.prize.0, machine.prize.1].iter().any(|&n| n < 0) {
+            return Err(AocError::new("Buttons and prizes can't be negative.").at_line(record.index(0)));
+        }
+        // Cramer's rule divides by this, and buttons along one line have more than one way to the prize, or none.
+        if machine.n_a_sad(0).1 == 0 {
+            return Err(AocError::new("Buttons A and B move the claw along the same line.").at_line(record.index(0)));
+        }
+        Ok(machine)
+    }).collect()
+}
+
+pub struct Machine {
+    a: (Num, Num),
+    b: (Num, Num),
+    prize: (Num, Num),
+}
+
+impl Display for Machine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Button A: X+{}, Y+{}", self.a.0, self.a.1)?;
+        writeln!(f, "Button B: X+{}, Y+{}", self.b.0, self.b.1)?;
+        writeln!(f, "Prize: X={}, Y={}", self.prize.0, self.prize.1)
+    }
+}
+
+impl Machine {
+    pub fn solve(&self) -> Option<Wide> {
+        self.cost(0)
+    }
+
+    pub fn solve_sad(&self) -> Option<Wide> {
+        self.cost(10000000000000)
+    }
+
+    /// Three tokens per press of A and one per press of B, if whole, non-negative numbers of presses win the prize.
+    /// A winning count can't be more than the prize's distance, so the cost always fits.
+    fn cost(&self, plus: Wide) -> Option<Wide> {
+        let (a_x, a_y) = self.n_a_sad(plus);
+        let (b_x, b_y) = self.n_b_sad(plus);
+        // is a_x / a_y an int? is b_x / b_y an int?
+        if a_y == 0 || a_x % a_y != 0 || b_x % b_y != 0 {
+            return None;
+        }
+        let (n_a, n_b) = (a_x / a_y, b_x / b_y);
+        (n_a >= 0 && n_b >= 0).then(|| n_a * 3 + n_b)
+    }
+
+    pub fn n_a(&self) -> f64 {
+        random_equality(self.a.0 as f64, self.a.1 as f64, self.b.0 as f64, self.b.1 as f64, self.prize.0 as f64, self.prize.1 as f64)
+    }
+
+    pub fn n_b(&self) -> f64 {
+        random_equality(self.b.0 as f64, self.b.1 as f64, self.a.0 as f64, self.a.1 as f64, self.prize.0 as f64, self.prize.1 as f64)
+    }
+
+    pub fn n_a_sad(&self, plus: Wide) -> (Wide, Wide) {
+        let (a, b, prize) = self.wide();
+        random_equality_parts(a.0, a.1, b.0, b.1, prize.0 + plus, prize.1 + plus)
+    }
+
+    pub fn n_b_sad(&self, plus: Wide) -> (Wide, Wide) {
+        let (a, b, prize) = self.wide();
+        random_equality_parts(b.0, b.1, a.0, a.1, prize.0 + plus, prize.1 + plus)
+    }
+
+    fn wide(&self) -> ((Wide, Wide), (Wide, Wide), (Wide, Wide)) {
+        let wide = |(x, y): (Num, Num)| (x as Wide, y as Wide);
+        (wide(self.a), wide(self.b), wide(self.prize))
+    }
+}
+
+pub fn random_equality(x1: f64, y1: f64, x2: f64, y2: f64, xp: f64, yp: f64) -> f64 {
+    (y2 * xp - x2 * yp) / (y2 * x1 - x2 * y1)
+}
+
+pub fn random_equality_parts(x1: Wide, y1: Wide, x2: Wide, y2: Wide, xp: Wide, yp: Wide) -> (Wide, Wide) {
+    (y2 * xp - x2 * yp, y2 * x1 - x2 * y1)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use std::time::Instant;
+    use super::{parse_input, Wide};
+    use crate::util::parsing;
+
+    #[test]
+    fn test_small() -> Result<(), Box<dyn Error>> {
+        let _start = Instant::now();
+        let lines = parsing::file_into_vec("files/day_13_small.txt")?;
+        let machines = parse_input(&lines)?;
+        let solutions: Vec<Option<Wide>> = machines.iter().map(|m| m.solve()).collect();
+        let expected: Vec<Option<Wide>> = vec![
+            Some(280),
+            None,
+            Some(200),
+            None,
+        ];
+
+        for idx in 0..solutions.len() {
+            assert_eq!(solutions[idx], expected[idx]);
+        }
+
+        let cost: Wide = solutions.into_iter().flatten().sum();
+        assert_eq!(cost, 480);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sad_small() -> Result<(), Box<dyn Error>> {
+        let _start = Instant::now();
+        let lines = parsing::file_into_vec("files/day_13_small.txt")?;
+        let machines = parse_input(&lines)?;
+        let solutions: Vec<Option<Wide>> = machines.iter().map(|m| m.solve_sad()).collect();
+        let expected: Vec<Option<Wide>> = vec![
+            None,
+            Some(0),
+            None,
+            Some(0),
+        ];
+
+        for idx in 0..solutions.len() {
+            println!("{}", idx);
+            assert_eq!(solutions[idx].is_some(), expected[idx].is_some());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() -> Result<(), Box<dyn Error>> {
+        let mut lines = parsing::file_into_vec("files/day_13_small.txt")?;
+        lines.push(String::new());
+        assert_eq!(parse_input(&lines)?.len(), 4);
+
+        lines.truncate(13);
+        let error = parse_input(&lines).err().unwrap();
+        assert_eq!(error.line, Some(13));
+
+        lines[5] = "Button B: X+84".to_string();
+        let error = parse_input(&lines).err().unwrap();
+        assert_eq!(error.line, Some(6));
+
+        let machine = |a: &str, b: &str, prize: &str| vec![format!("Button A: {}", a), format!("Button B: {}", b), format!("Prize: {}", prize)];
+        let error = parse_input(&machine("X+1, Y+1", "X+2, Y+2", "X=10, Y=10")).err().unwrap();
+        assert_eq!(error.line, Some(1));
+        assert!(parse_input(&machine("X+-1, Y+1", "X+2, Y+2", "X=10, Y=10")).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_far_prize() -> Result<(), Box<dyn Error>> {
+        let lines = ["Button A: X+1, Y+0", "Button B: X+0, Y+1", "Prize: X=9223372036854775000, Y=9223372036854775000"];
+        let machines = parse_input(&lines.map(String::from).to_vec())?;
+        assert_eq!(machines[0].solve(), Some(4 * 9223372036854775000));
+        assert_eq!(machines[0].solve_sad(), Some(4 * (9223372036854775000 + 10000000000000)));
+        Ok(())
+    }
+}
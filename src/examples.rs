@@ -0,0 +1,134 @@
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use crate::runner::{self, Part};
+use crate::util::parsing;
+use crate::verify::{self, Outcome};
+use crate::AocError;
+
+pub const DEFAULT_EXAMPLES: &str = "files/examples.txt";
+
+/// An example input from a puzzle's text, with the answers the text gives for it.
+#[derive(Debug, PartialEq)]
+pub struct Example {
+    pub day: u8,
+    pub path: PathBuf,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Example {
+    pub fn expected(&self, part: Part) -> Option<&String> {
+        match part {
+            Part::One => self.part_1.as_ref(),
+            Part::Two => self.part_2.as_ref(),
+        }
+    }
+}
+
+pub fn load<P: AsRef<Path>>(path: P) -> Result<Vec<Example>, Box<dyn Error>> {
+    let path = path.as_ref();
+    let lines = parsing::file_into_vec(path)
+        .map_err(|e| format!("Couldn't read {}: {}", path.display(), e))?;
+    Ok(parse(&lines).map_err(|e| e.in_file(path))?)
+}
+
+/// Reads `day file part_1 part_2` lines, where `-` means the answer isn't known.
+/// Blank lines and `#` comments are skipped.
+pub fn parse(lines: &Vec<String>) -> Result<Vec<Example>, AocError> {
+    let mut examples = Vec::new();
+    for (idx, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = trimmed.split_whitespace().collect();
+        if fields.len() != 4 {
+            return Err(AocError::new("Expected 'day file part_1 part_2'.").at(idx, line));
+        }
+        let day = fields[0].parse::<u8>()
+            .map_err(|_| AocError::new(format!("'{}' is not a day.", fields[0])).at(idx, line))?;
+        let answer = |field: &str| (field != "-").then(|| field.to_string());
+        examples.push(Example {
+            day,
+            path: PathBuf::from(fields[1]),
+            part_1: answer(fields[2]),
+            part_2: answer(fields[3]),
+        });
+    }
+    Ok(examples)
+}
+
+/// Solves an example and compares each part that has an expected answer.
+pub fn check(example: &Example) -> Vec<(Part, Outcome)> {
+    let answers = runner::read_input(&example.path)
+        .map_err(|e| e.to_string())
+        .map(|lines| runner::catch_panic(|| {
+            runner::solve(example.day, &lines, None).map_err(|e| e.in_file(&example.path))
+        }));
+    [Part::One, Part::Two].into_iter()
+        .filter(|&part| example.expected(part).is_some())
+        .map(|part| {
+            let outcome = match &answers {
+                Err(e) => Outcome::Error(e.clone()),
+                Ok(Err(message)) => Outcome::Panic(message.clone()),
+                Ok(Ok(Err(e))) => Outcome::Error(e.to_string()),
+                Ok(Ok(Ok(answers))) => verify::compare(example.expected(part), answers.get(part)),
+            };
+            (part, outcome)
+        })
+        .collect()
+}
+
+/// Checks the examples for `days`, or all of them when `days` is empty, printing one line per part.
+/// Returns whether all of them passed.
+pub fn check_examples(examples: &[Example], days: &[u8]) -> bool {
+    let mut checked = 0;
+    let mut failures = 0;
+    for example in examples.iter().filter(|example| days.is_empty() || days.contains(&example.day)) {
+        for (part, outcome) in check(example) {
+            println!("Day {:>2} {} part {}: {}", example.day, example.path.display(), part, outcome);
+            checked += 1;
+            if !outcome.passed() {
+                failures += 1;
+            }
+        }
+    }
+    if failures == 0 {
+        println!("All {} example answers match.", checked);
+    } else {
+        println!("{} of {} example answers failed.", failures, checked);
+    }
+    failures == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use super::{check, load, parse, Example, DEFAULT_EXAMPLES};
+
+    #[test]
+    fn test_parse() {
+        let lines: Vec<String> = "# comment\n\n12 files/day_12_e.txt - 236\n"
+            .split("\n").map(|x| x.to_string()).collect();
+        assert_eq!(parse(&lines).unwrap(), vec![Example {
+            day: 12,
+            path: PathBuf::from("files/day_12_e.txt"),
+            part_1: None,
+            part_2: Some("236".to_string()),
+        }]);
+
+        let bad: Vec<String> = vec!["12 files/day_12_e.txt 236".to_string()];
+        assert_eq!(parse(&bad).unwrap_err().line, Some(1));
+    }
+
+    /// Checks every entry in `files/examples.txt`, naming each part that didn't match rather than stopping at the first.
+    #[test]
+    fn test_examples() {
+        let failures: Vec<String> = load(DEFAULT_EXAMPLES).unwrap().iter()
+            .flat_map(|example| check(example).into_iter()
+                .filter(|(_, outcome)| !outcome.passed())
+                .map(move |(part, outcome)| format!("Day {} {} part {}: {}", example.day, example.path.display(), part, outcome)))
+            .collect();
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
@@ -0,0 +1,120 @@
+use crate::runner::{self, Answers};
+use crate::util::parsing::{self, LoadOptions};
+use crate::AocError;
+
+/// What the targets in `fuzz/` call: any bytes go through loading, parsing and both parts of `day`.
+/// Bad input should come back as an `Err`; a panic is a bug.
+pub fn solve_bytes(day: u8, data: &[u8]) -> Result<Answers, AocError> {
+    let lines = parsing::bytes_into_lines(data, LoadOptions::default())?;
+    runner::solve(day, &lines, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use proptest::sample::Index;
+    use super::solve_bytes;
+    use crate::generate::{generate, GenOptions};
+    use crate::runner::{catch_panic, LAST_DAY};
+
+    /// The characters each day's input is made of, so random inputs get past the first check now and then.
+    fn alphabet(day: u8) -> &'static str {
+        match day {
+            1 | 2 | 11 => "0123456789  -\n",
+            3 => "mul(,)0123456789don't\n",
+            4 => "XMAS.\n",
+            5 => "0123456789|,\n\n",
+            6 => "..#^>v<XO\n",
+            7 => "0123456789: \n",
+            8 => "..aA0#\n",
+            9 => "0123456789\n",
+            10 => "0123456789.\n",
+            12 => "ABCab\n",
+            13 => "Button AB:XY+=,Prize 0123456789\n\n",
+            _ => "",
+        }
+    }
+
+    /// Short strings of a day's characters, or of any bytes at all.
+    fn shaped() -> impl Strategy<Value = (u8, Vec<u8>)> {
+        (1..=LAST_DAY).prop_flat_map(|day| {
+            let chars: Vec<u8> = alphabet(day).bytes().collect();
+            let shaped = prop::collection::vec(prop::sample::select(chars), 0..48);
+            let raw = prop::collection::vec(any::<u8>(), 0..24);
+            (Just(day), prop_oneof![4 => shaped, 1 => raw])
+        })
+    }
+
+    /// A small input from `generate` with a few bytes swapped for others of the day's characters,
+    /// so it's mostly well formed and gets into the solvers.
+    fn mutated() -> impl Strategy<Value = (u8, Vec<u8>)> {
+        let edits = prop::collection::vec((any::<Index>(), any::<Index>()), 0..4);
+        (1..=LAST_DAY, any::<u64>(), 1..4usize, 0.0..0.5f64, edits).prop_map(|(day, seed, size, density, edits)| {
+            let options = GenOptions {
+                size: Some(size),
+                seed,
+                density: Some(density),
+                frequencies: None,
+            };
+            let mut data = generate(day, &options).map(|lines| lines.join("\n")).unwrap_or_default().into_bytes();
+            let chars = alphabet(day).as_bytes();
+            for (at, with) in edits {
+                if !data.is_empty() {
+                    let at = at.index(data.len());
+                    data[at] = chars[with.index(chars.len())];
+                }
+            }
+            (day, data)
+        })
+    }
+
+    /// Rectangles for the grid days. Day 6 gets exactly one guard, so the map is one it has to walk.
+    fn grids() -> impl Strategy<Value = (u8, Vec<u8>)> {
+        let day = prop::sample::select(vec![4u8, 6, 8, 10, 12]);
+        let cells = prop::collection::vec(any::<Index>(), 64);
+        (day, 1..8usize, 1..8usize, cells, any::<Index>(), any::<Index>()).prop_map(|(day, width, height, cells, guard, dir)| {
+            let chars = match day {
+                4 => "XMAS.",
+                6 => "...#",
+                8 => "....aA0",
+                10 => "0123456789",
+                _ => "AAB",
+            }.as_bytes();
+            let mut grid: Vec<u8> = cells[..width * height].iter().map(|idx| chars[idx.index(chars.len())]).collect();
+            if day == 6 {
+                let at = guard.index(grid.len());
+                grid[at] = b"^>v<"[dir.index(4)];
+            }
+            let lines: Vec<&[u8]> = grid.chunks(width).collect();
+            (day, lines.join(&b'\n'))
+        })
+    }
+
+    /// Day 13 machines made of awkward numbers: zeros, small buttons that often point the same way,
+    /// and prizes close to the top of an i64.
+    fn machines() -> impl Strategy<Value = (u8, Vec<u8>)> {
+        let number = prop::sample::select(vec![0i64, 1, 2, 3, 4, 6, 94, 9_223_372_036_854_775_000, i64::MAX]);
+        prop::collection::vec(prop::array::uniform6(number), 1..4).prop_map(|machines| {
+            let text: Vec<String> = machines.iter().map(|[ax, ay, bx, by, px, py]| {
+                format!("Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}", ax, ay, bx, by, px, py)
+            }).collect();
+            (13, text.join("\n\n").into_bytes())
+        })
+    }
+
+    fn inputs() -> impl Strategy<Value = (u8, Vec<u8>)> {
+        prop_oneof![2 => shaped(), 2 => mutated(), 1 => grids(), 1 => machines()]
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(3000))]
+
+        /// A quick stand-in for the fuzz targets that runs with `cargo test`.
+        #[test]
+        fn no_day_panics(input in inputs()) {
+            let (day, data) = input;
+            let result = catch_panic(|| solve_bytes(day, &data).map(|_| ()));
+            prop_assert!(result.is_ok(), "day {} panicked on {:?}: {:?}", day, String::from_utf8_lossy(&data), result);
+        }
+    }
+}
@@ -0,0 +1,327 @@
+use std::fmt::Write;
+use crate::days::day_06;
+use crate::runner::no_solution;
+use crate::util::rng::Rng;
+use crate::AocError;
+
+/// Antenna frequencies on day 8 are single letters or digits.
+const FREQUENCIES: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// What to generate. Anything left as `None` gets a default close to the real inputs.
+#[derive(Clone, Debug, Default)]
+pub struct GenOptions {
+    /// How big the input is, in each day's natural unit; see `size_unit`.
+    pub size: Option<usize>,
+    pub seed: u64,
+    /// Chance of a cell holding an obstacle (day 6) or an antenna (day 8), of a report being broken (day 2),
+    /// or of a plot starting a new region (day 12).
+    pub density: Option<f64>,
+    /// How many antenna frequencies there are on day 8.
+    pub frequencies: Option<usize>,
+}
+
+/// What `--size` counts for each day.
+pub fn size_unit(day: u8) -> Option<&'static str> {
+    let unit = match day {
+        1 => "pairs of location ids",
+        2 => "reports",
+        3 => "instructions",
+        4 | 6 | 8 | 10 | 12 => "cells per side",
+        5 => "updates",
+        7 => "equations",
+        9 => "files",
+        11 => "stones",
+        13 => "claw machines",
+        _ => return None,
+    };
+    Some(unit)
+}
+
+fn default_size(day: u8) -> usize {
+    match day {
+        1 | 2 => 1000,
+        3 => 700,
+        4 | 12 => 140,
+        5 => 200,
+        6 => 130,
+        7 => 850,
+        8 => 50,
+        9 => 10000,
+        10 => 57,
+        11 => 8,
+        13 => 320,
+        _ => 0,
+    }
+}
+
+/// Writes a random input for `day` in the puzzle's format, the same for the same options every time.
+pub fn generate(day: u8, options: &GenOptions) -> Result<Vec<String>, AocError> {
+    let size = options.size.unwrap_or_else(|| default_size(day));
+    if size == 0 {
+        return Err("The size has to be at least 1.".into());
+    }
+    if let Some(density) = options.density {
+        if !(0.0..=1.0).contains(&density) {
+            return Err(AocError::new(format!("The density has to be between 0 and 1, not {}.", density)));
+        }
+    }
+    let mut rng = Rng::new(options.seed);
+    let lines = match day {
+        1 => location_ids(&mut rng, size),
+        2 => reports(&mut rng, size, options.density.unwrap_or(0.4)),
+        3 => memory(&mut rng, size),
+        4 => grid(size, |_, _| *rng.pick(&['X', 'M', 'A', 'S'])),
+        5 => print_queue(&mut rng, size),
+        6 => lab(&mut rng, size, options.density.unwrap_or(0.05))?,
+        7 => equations(&mut rng, size),
+        8 => antennas(&mut rng, size, options.density.unwrap_or(0.05), options.frequencies.unwrap_or(36))?,
+        9 => vec![(0..size * 2 - 1).map(|i| {
+            let low = if i % 2 == 0 { 1 } else { 0 };
+            char::from(b'0' + rng.range(low, 9) as u8)
+        }).collect()],
+        10 => topographic_map(&mut rng, size),
+        11 => vec![(0..size).map(|_| rng.below(1_000_000).to_string()).collect::<Vec<_>>().join(" ")],
+        12 => garden(&mut rng, size, options.density.unwrap_or(0.1)),
+        13 => claw_machines(&mut rng, size),
+        _ => return Err(no_solution(day)),
+    };
+    Ok(lines)
+}
+
+fn grid(size: usize, mut cell: impl FnMut(usize, usize) -> char) -> Vec<String> {
+    (0..size).map(|y| (0..size).map(|x| cell(x, y)).collect()).collect()
+}
+
+fn location_ids(rng: &mut Rng, size: usize) -> Vec<String> {
+    // Reusing ids from the left list gives part 2 some matches to count.
+    let left: Vec<u64> = (0..size).map(|_| rng.range(10000, 99999)).collect();
+    left.iter().map(|&a| {
+        let b = if rng.chance(0.2) { *rng.pick(&left) } else { rng.range(10000, 99999) };
+        format!("{}   {}", a, b)
+    }).collect()
+}
+
+/// Safe reports, some of which get one level changed.
+fn reports(rng: &mut Rng, size: usize, broken: f64) -> Vec<String> {
+    (0..size).map(|_| {
+        let len = rng.range(5, 8) as usize;
+        let ascending = rng.chance(0.5);
+        let mut level = if ascending { rng.range(1, 60) } else { rng.range(40, 99) } as i64;
+        let mut levels = Vec::with_capacity(len);
+        for _ in 0..len {
+            levels.push(level);
+            let step = rng.range(1, 3) as i64;
+            level += if ascending { step } else { -step };
+        }
+        if rng.chance(broken) {
+            let idx = rng.below(len as u64) as usize;
+            levels[idx] = rng.range(1, 99) as i64;
+        }
+        levels.iter().map(|level| level.to_string()).collect::<Vec<_>>().join(" ")
+    }).collect()
+}
+
+/// Real instructions among almost-instructions and noise, on lines of about 3000 characters.
+fn memory(rng: &mut Rng, size: usize) -> Vec<String> {
+    const NOISE: &[&str] = &["what()", "from()", "who()", "]", "[", "%", "'", ",", "*", "+", "mul", "mul[", "mul(", "do", "don't", "(", ")", " ", "@", "how()"];
+    let mut lines = vec![String::new()];
+    for _ in 0..size {
+        let line = lines.last_mut().unwrap();
+        match rng.below(10) {
+            0 => line.push_str("do()"),
+            1 => line.push_str("don't()"),
+            2 => write!(line, "mul({},{}]", rng.range(1, 999), rng.range(1, 999)).unwrap(),
+            3 => write!(line, "mul({} ,{})", rng.range(1, 999), rng.range(1, 999)).unwrap(),
+            _ => write!(line, "mul({},{})", rng.range(1, 999), rng.range(1, 999)).unwrap(),
+        }
+        for _ in 0..rng.range(0, 3) {
+            let noise = *rng.pick(NOISE);
+            line.push_str(noise);
+        }
+        if line.len() > 3000 {
+            lines.push(String::new());
+        }
+    }
+    lines.retain(|line| !line.is_empty());
+    lines
+}
+
+/// Every pair of pages gets a rule, from one random order of all of them, like the real input.
+fn print_queue(rng: &mut Rng, size: usize) -> Vec<String> {
+    let mut pages: Vec<u64> = (10..=99).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+    let mut lines = Vec::new();
+    for i in 0..pages.len() {
+        for j in (i + 1)..pages.len() {
+            lines.push(format!("{}|{}", pages[i], pages[j]));
+        }
+    }
+    rng.shuffle(&mut lines);
+    lines.push(String::new());
+    for _ in 0..size {
+        let len = rng.range(2, 11) as usize * 2 + 1;
+        let mut update: Vec<usize> = (0..pages.len()).collect();
+        rng.shuffle(&mut update);
+        update.truncate(len);
+        if rng.chance(0.5) {
+            update.sort();
+        }
+        lines.push(update.iter().map(|&idx| pages[idx].to_string()).collect::<Vec<_>>().join(","));
+    }
+    lines
+}
+
+/// The guard starts somewhere they walk off the map from, like in the real input, rather than pacing a loop forever.
+fn lab(rng: &mut Rng, size: usize, density: f64) -> Result<Vec<String>, AocError> {
+    const ATTEMPTS: usize = 100;
+    let cells: Vec<Vec<char>> = (0..size).map(|_| (0..size).map(|_| if rng.chance(density) { '#' } else { '.' }).collect()).collect();
+    let mut open: Vec<(usize, usize)> = (0..size * size).map(|i| (i % size, i / size)).filter(|&(x, y)| cells[y][x] == '.').collect();
+    rng.shuffle(&mut open);
+    open.into_iter().take(ATTEMPTS).map(|start| with_guard(&cells, start)).find(guard_leaves)
+        .ok_or_else(|| "Couldn't find a spot the guard walks off the map from; try a lower density.".into())
+}
+
+/// Walks a guard facing up from `start` until they leave the map or come back to a spot facing the same way.
+/// The lab's rows with the guard facing up at `(x, y)`.
+fn with_guard(cells: &Vec<Vec<char>>, (x, y): (usize, usize)) -> Vec<String> {
+    cells.iter().enumerate().map(|(row_y, row)| {
+        row.iter().enumerate().map(|(row_x, &c)| if (row_x, row_y) == (x, y) { '^' } else { c }).collect()
+    }).collect()
+}
+
+fn guard_leaves(lines: &Vec<String>) -> bool {
+    day_06::parse_map(lines).is_ok_and(|map| day_06::run_map(&map).is_some())
+}
+
+/// Half the equations are built from their numbers, so both parts have something to find.
+fn equations(rng: &mut Rng, size: usize) -> Vec<String> {
+    // Small enough that a million left sides still add up to less than `i64::MAX`.
+    const MAX_LEFT: u64 = 1_000_000_000_000;
+    (0..size).map(|_| {
+        let right: Vec<u64> = (0..rng.range(2, 12)).map(|_| rng.range(1, 999)).collect();
+        let built = right[1..].iter().try_fold(right[0], |acc, &b| match rng.below(3) {
+            0 => acc.checked_add(b),
+            1 => acc.checked_mul(b),
+            _ => format!("{}{}", acc, b).parse().ok(),
+        });
+        let left = match built {
+            Some(left) if left <= MAX_LEFT && rng.chance(0.5) => left,
+            _ => rng.range(1, MAX_LEFT),
+        };
+        let right: Vec<String> = right.iter().map(|n| n.to_string()).collect();
+        format!("{}: {}", left, right.join(" "))
+    }).collect()
+}
+
+fn antennas(rng: &mut Rng, size: usize, density: f64, frequencies: usize) -> Result<Vec<String>, AocError> {
+    if !(1..=FREQUENCIES.len()).contains(&frequencies) {
+        return Err(AocError::new(format!("There can be 1 to {} frequencies, not {}.", FREQUENCIES.len(), frequencies)));
+    }
+    let frequencies: Vec<char> = FREQUENCIES.chars().take(frequencies).collect();
+    Ok(grid(size, |_, _| if rng.chance(density) { *rng.pick(&frequencies) } else { '.' }))
+}
+
+/// Random heights with some gentle trails from 0 to 9 walked over them.
+fn topographic_map(rng: &mut Rng, size: usize) -> Vec<String> {
+    let mut heights: Vec<Vec<u8>> = (0..size).map(|_| (0..size).map(|_| rng.below(10) as u8).collect()).collect();
+    for _ in 0..(size * size / 40).max(1) {
+        let (mut x, mut y) = (rng.below(size as u64) as usize, rng.below(size as u64) as usize);
+        let mut trail = vec![(x, y)];
+        heights[y][x] = 0;
+        for height in 1..=9 {
+            let steps = [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)];
+            let open: Vec<(usize, usize)> = steps.into_iter().filter(|&(x, y)| x < size && y < size && !trail.contains(&(x, y))).collect();
+            if open.is_empty() {
+                break;
+            }
+            (x, y) = *rng.pick(&open);
+            trail.push((x, y));
+            heights[y][x] = height;
+        }
+    }
+    heights.into_iter().map(|row| row.into_iter().map(|h| char::from(b'0' + h)).collect()).collect()
+}
+
+/// Each plot copies the plant above or to its left, unless it starts a new region.
+fn garden(rng: &mut Rng, size: usize, new_region: f64) -> Vec<String> {
+    let mut plots: Vec<Vec<char>> = Vec::with_capacity(size);
+    for y in 0..size {
+        let mut row: Vec<char> = Vec::with_capacity(size);
+        for x in 0..size {
+            let neighbors: Vec<char> = [(x > 0).then(|| row[x - 1]), (y > 0).then(|| plots[y - 1][x])].into_iter().flatten().collect();
+            let plant = if neighbors.is_empty() || rng.chance(new_region) {
+                char::from(b'A' + rng.below(26) as u8)
+            } else {
+                *rng.pick(&neighbors)
+            };
+            row.push(plant);
+        }
+        plots.push(row);
+    }
+    plots.into_iter().map(|row| row.into_iter().collect()).collect()
+}
+
+/// Half the prizes can be reached with at most 100 presses of each button.
+fn claw_machines(rng: &mut Rng, size: usize) -> Vec<String> {
+    let mut lines = Vec::with_capacity(size * 4);
+    for idx in 0..size {
+        let a = (rng.range(10, 99), rng.range(10, 99));
+        // Buttons along one line don't make a puzzle, and day 13 turns them down.
+        let b = loop {
+            let b = (rng.range(10, 99), rng.range(10, 99));
+            if a.0 * b.1 != a.1 * b.0 {
+                break b;
+            }
+        };
+        let prize = if rng.chance(0.5) {
+            let (i, j) = (rng.range(0, 100), rng.range(0, 100));
+            (a.0 * i + b.0 * j, a.1 * i + b.1 * j)
+        } else {
+            (rng.range(1000, 20000), rng.range(1000, 20000))
+        };
+        if idx > 0 {
+            lines.push(String::new());
+        }
+        lines.push(format!("Button A: X+{}, Y+{}", a.0, a.1));
+        lines.push(format!("Button B: X+{}, Y+{}", b.0, b.1));
+        lines.push(format!("Prize: X={}, Y={}", prize.0, prize.1));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, GenOptions};
+    use crate::runner::{self, LAST_DAY};
+
+    #[test]
+    fn test_generate() {
+        for day in 1..=LAST_DAY {
+            let options = GenOptions { size: Some(12), seed: 3, ..Default::default() };
+            let lines = generate(day, &options).unwrap();
+            assert_eq!(lines, generate(day, &options).unwrap(), "day {}", day);
+            assert_ne!(lines, generate(day, &GenOptions { seed: 4, ..options.clone() }).unwrap(), "day {}", day);
+            if let Err(e) = runner::solve(day, &lines, None) {
+                panic!("day {} can't solve its own input: {}\n{}", day, e, lines.join("\n"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_knobs() {
+        let count = |lines: &Vec<String>, c: char| lines.iter().flat_map(|line| line.chars()).filter(|&x| x == c).count();
+        let sparse = generate(6, &GenOptions { size: Some(100), density: Some(0.01), ..Default::default() }).unwrap();
+        let dense = generate(6, &GenOptions { size: Some(100), density: Some(0.3), ..Default::default() }).unwrap();
+        assert_eq!(sparse.len(), 100);
+        assert!(sparse.iter().all(|line| line.len() == 100));
+        assert!(count(&sparse, '#') * 10 < count(&dense, '#'));
+        assert_eq!(count(&dense, '^'), 1);
+
+        let two = generate(8, &GenOptions { size: Some(40), frequencies: Some(2), ..Default::default() }).unwrap();
+        assert!(two.iter().flat_map(|line| line.chars()).all(|c| ".01".contains(c)));
+        assert!(generate(8, &GenOptions { frequencies: Some(63), ..Default::default() }).is_err());
+        assert!(generate(6, &GenOptions { density: Some(1.5), ..Default::default() }).is_err());
+        assert!(generate(14, &GenOptions::default()).is_err());
+    }
+}
@@ -0,0 +1,23 @@
+pub mod alloc_stats;
+pub mod bench;
+pub mod client;
+pub mod days;
+pub mod examples;
+pub mod fuzz;
+pub mod generate;
+pub mod readme;
+pub mod render;
+pub mod run_all;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod stepper;
+pub mod util;
+pub mod verify;
+
+pub use solution::Solution;
+pub use util::error::AocError;
+
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static ALLOC: alloc_stats::CountingAlloc = alloc_stats::CountingAlloc;
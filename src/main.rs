@@ -1,3 +1,422 @@
-fn main() {
-    println!("Hello, world!");
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+use advent_2024::{alloc_stats, bench, client, examples, generate, readme, run_all, scaffold, stepper};
+use advent_2024::client::{Client, Fetched, Verdict};
+use advent_2024::generate::GenOptions;
+use advent_2024::runner::{self, Part, RunOptions};
+use advent_2024::stepper::Stepper;
+use advent_2024::util::log::{self, Level};
+use advent_2024::verify::{self, AnswerBook};
+
+const USAGE: &str = "Usage: advent_2024 [-v | -vv | --trace] <command>
+    advent_2024 run <day> [--part <1|2>] [--input <path|->] [--trim] [--format <text|json>] [--spans] [--profile [<seconds>]]
+        [--render <out.png>]   (days 6, 8, 10 and 12)
+    advent_2024 all [<day>...] [--threads <n>]
+    advent_2024 verify [<day>...] [--answers <path>]
+    advent_2024 examples [<day>...] [--examples <path>]
+    advent_2024 new <day>
+    advent_2024 fetch <day> [--output <path>] [--base-url <url>] [--session-file <path>]
+    advent_2024 submit <day> <1|2> [--input <path>] [--answers <path>] [--wait] [--base-url <url>] [--session-file <path>]
+    advent_2024 bench [<day>...] [--part <1|2>] [--input <path>] [--runs <n>] [--warmup <n>]
+    advent_2024 allocs [<day>...] [--part <1|2>] [--input <path>]   (built with --features count-allocs)
+    advent_2024 readme [<day>...] [--readme <path>] [--machine <name>] [--runs <n>] [--warmup <n>]
+    advent_2024 generate <day> [--size <n>] [--seed <n>] [--density <0-1>] [--frequencies <n>] [--output <path>]
+    advent_2024 step [--input <path>] [--view <width>x<height>]   (steps the day 6 guard; ? lists the commands)
+
+-v logs debug messages and -vv or --trace logs everything, to stderr. AOC_LOG=<info|debug|trace> does the same.
+Release builds only log with --features log.";
+
+/// Long enough for a sampling profiler to collect a useful number of stacks.
+const DEFAULT_PROFILE_SECONDS: f64 = 10.0;
+
+fn main() -> ExitCode {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    if let Err(e) = set_log_level(&mut args) {
+        eprintln!("{}", e);
+        return ExitCode::FAILURE;
+    }
+    match dispatch(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Takes the verbosity flags out of `args`, wherever they are, falling back to `AOC_LOG`.
+fn set_log_level(args: &mut Vec<String>) -> Result<(), Box<dyn Error>> {
+    log::init_from_env()?;
+    let mut level = None;
+    args.retain(|arg| {
+        let flag = match arg.as_str() {
+            "-v" => Some(Level::Debug),
+            "-vv" | "--trace" => Some(Level::Trace),
+            _ => None,
+        };
+        level = level.max(flag);
+        flag.is_none()
+    });
+    if level.is_some() {
+        if !log::COMPILED {
+            eprintln!("Logging is compiled out of release builds; rebuild with --features log.");
+        }
+        log::set_level(level);
+    }
+    Ok(())
+}
+
+fn dispatch(args: &[String]) -> Result<(), Box<dyn Error>> {
+    match args.first().map(String::as_str) {
+        Some("run") => runner::run(&parse_run(&args[1..])?),
+        Some("all") => run_all(&args[1..]),
+        Some("verify") | Some("--verify") => run_verify(&args[1..]),
+        Some("examples") => run_examples(&args[1..]),
+        Some("new") => run_new(&args[1..]),
+        Some("fetch") => run_fetch(&args[1..]),
+        Some("submit") => run_submit(&args[1..]),
+        Some("bench") => run_bench(&args[1..]),
+        Some("allocs") => run_allocs(&args[1..]),
+        Some("readme") => run_readme(&args[1..]),
+        Some("generate") => run_generate(&args[1..]),
+        Some("step") => run_step(&args[1..]),
+        Some(command) => Err(format!("Unknown command '{}'.\n{}", command, USAGE).into()),
+        None => Err(USAGE.into()),
+    }
+}
+
+fn parse_run(args: &[String]) -> Result<RunOptions, Box<dyn Error>> {
+    let mut args = args.iter();
+    let day = parse_day(args.next().ok_or(USAGE)?)?;
+    let mut options = RunOptions::new(day);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => options.part = Some(args.next().ok_or(USAGE)?.parse()?),
+            "--input" => options.input = PathBuf::from(args.next().ok_or(USAGE)?),
+            "--trim" => options.load.trim_trailing = true,
+            "--spans" => options.spans = true,
+            "--render" => options.render = Some(PathBuf::from(args.next().ok_or(USAGE)?)),
+            "--profile" => {
+                let seconds = match args.clone().next().map(|next| next.parse::<f64>()) {
+                    Some(Ok(seconds)) => {
+                        args.next();
+                        seconds
+                    },
+                    _ => DEFAULT_PROFILE_SECONDS,
+                };
+                options.profile = Some(Duration::from_secs_f64(seconds));
+            },
+            "--format" => options.format = args.next().ok_or(USAGE)?.parse()?,
+            _ => return Err(format!("Unknown argument '{}'.\n{}", arg, USAGE).into()),
+        }
+    }
+    Ok(options)
+}
+
+fn parse_day(arg: &str) -> Result<u8, Box<dyn Error>> {
+    arg.parse::<u8>().map_err(|_| format!("Day must be a number, not '{}'.", arg).into())
+}
+
+fn run_all(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut args = args.iter();
+    let mut threads = 1;
+    let mut days = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--threads" => threads = args.next().ok_or(USAGE)?.parse()?,
+            _ => days.push(parse_day(arg)?),
+        }
+    }
+    if days.is_empty() {
+        days = (1..=runner::LAST_DAY).collect();
+    }
+    if run_all::run_all(&days, threads) {
+        Ok(())
+    } else {
+        Err("Some days failed.".into())
+    }
+}
+
+fn run_verify(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut args = args.iter();
+    let mut answers = PathBuf::from(verify::DEFAULT_ANSWERS);
+    let mut days = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" => answers = PathBuf::from(args.next().ok_or(USAGE)?),
+            _ => days.push(parse_day(arg)?),
+        }
+    }
+    if days.is_empty() {
+        days = (1..=runner::LAST_DAY).collect();
+    }
+    let book = AnswerBook::load(&answers)?;
+    if verify::verify(&book, &days) {
+        Ok(())
+    } else {
+        Err("Verification failed.".into())
+    }
+}
+
+fn run_examples(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut args = args.iter();
+    let mut path = PathBuf::from(examples::DEFAULT_EXAMPLES);
+    let mut days = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--examples" => path = PathBuf::from(args.next().ok_or(USAGE)?),
+            _ => days.push(parse_day(arg)?),
+        }
+    }
+    let examples = examples::load(&path)?;
+    if examples::check_examples(&examples, &days) {
+        Ok(())
+    } else {
+        Err("Some examples failed.".into())
+    }
+}
+
+fn run_new(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let [day] = args else {
+        return Err(USAGE.into());
+    };
+    let day = parse_day(day)?;
+    let scaffold = scaffold::new_day(Path::new("."), day)?;
+    for path in &scaffold.created {
+        println!("Created {}", path.display());
+    }
+    for path in &scaffold.updated {
+        println!("Updated {}", path.display());
+    }
+    Ok(())
+}
+
+fn run_fetch(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut args = args.iter();
+    let day = parse_day(args.next().ok_or(USAGE)?)?;
+    let mut output = runner::default_input(day);
+    let mut base_url: Option<String> = None;
+    let mut session_file: Option<PathBuf> = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--output" => output = PathBuf::from(args.next().ok_or(USAGE)?),
+            "--base-url" => base_url = Some(args.next().ok_or(USAGE)?.clone()),
+            "--session-file" => session_file = Some(PathBuf::from(args.next().ok_or(USAGE)?)),
+            _ => return Err(format!("Unknown argument '{}'.\n{}", arg, USAGE).into()),
+        }
+    }
+    if output.exists() {
+        println!("{} is already there.", output.display());
+        return Ok(());
+    }
+    let client = Client::from_env(base_url.as_deref(), session_file.as_deref())?;
+    match client.fetch_input(day, &output)? {
+        Fetched::Downloaded => println!("Saved day {} of {} to {}.", day, client::YEAR, output.display()),
+        Fetched::Cached => println!("{} is already there.", output.display()),
+    }
+    Ok(())
+}
+
+fn run_submit(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut args = args.iter();
+    let day = parse_day(args.next().ok_or(USAGE)?)?;
+    let part: Part = args.next().ok_or(USAGE)?.parse()?;
+    let mut input = runner::default_input(day);
+    let mut answers = PathBuf::from(verify::DEFAULT_ANSWERS);
+    let mut wait = false;
+    let mut base_url: Option<String> = None;
+    let mut session_file: Option<PathBuf> = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => input = PathBuf::from(args.next().ok_or(USAGE)?),
+            "--answers" => answers = PathBuf::from(args.next().ok_or(USAGE)?),
+            "--wait" => wait = true,
+            "--base-url" => base_url = Some(args.next().ok_or(USAGE)?.clone()),
+            "--session-file" => session_file = Some(PathBuf::from(args.next().ok_or(USAGE)?)),
+            _ => return Err(format!("Unknown argument '{}'.\n{}", arg, USAGE).into()),
+        }
+    }
+    let lines = runner::read_input(&input)?;
+    let answer = runner::solve(day, &lines, Some(part)).map_err(|e| e.in_file(&input))?
+        .get(part).cloned().ok_or("The solver gave no answer.")?;
+    let mut book = AnswerBook::load(&answers)?;
+    if let Some(recorded) = book.get(day, part) {
+        if *recorded == answer {
+            println!("Day {} part {}: {} is already recorded as correct.", day, part, answer);
+            return Ok(());
+        }
+    }
+    let client = Client::from_env(base_url.as_deref(), session_file.as_deref())?;
+    println!("Submitting {} for day {} part {}.", answer, day, part);
+    let mut verdict = client.submit(day, part, &answer)?;
+    if let (Verdict::Wait(seconds), true) = (&verdict, wait) {
+        println!("{}", verdict);
+        thread::sleep(Duration::from_secs(*seconds + 1));
+        verdict = client.submit(day, part, &answer)?;
+    }
+    println!("{}", verdict);
+    match verdict {
+        Verdict::Correct => {
+            book.record(&answers, day, part, &answer)?;
+            println!("Recorded in {}.", answers.display());
+            Ok(())
+        },
+        Verdict::AlreadySolved => Ok(()),
+        _ => Err("The answer wasn't accepted.".into()),
+    }
+}
+
+fn run_bench(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut args = args.iter();
+    let mut days = Vec::new();
+    let mut part: Option<Part> = None;
+    let mut input: Option<PathBuf> = None;
+    let mut runs = bench::DEFAULT_RUNS;
+    let mut warmup = bench::DEFAULT_WARMUP;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => part = Some(args.next().ok_or(USAGE)?.parse()?),
+            "--input" => input = Some(PathBuf::from(args.next().ok_or(USAGE)?)),
+            "--runs" => runs = args.next().ok_or(USAGE)?.parse()?,
+            "--warmup" => warmup = args.next().ok_or(USAGE)?.parse()?,
+            _ => days.push(parse_day(arg)?),
+        }
+    }
+    if days.is_empty() {
+        days = (1..=runner::LAST_DAY).collect();
+    }
+    if input.is_some() && days.len() != 1 {
+        return Err("--input needs exactly one day.".into());
+    }
+    for day in days {
+        let path = input.clone().unwrap_or_else(|| runner::default_input(day));
+        let lines = runner::read_input(&path)?;
+        println!("{}", bench::bench_day(day, &lines, part, warmup, runs)?);
+    }
+    Ok(())
+}
+
+fn run_allocs(args: &[String]) -> Result<(), Box<dyn Error>> {
+    if !alloc_stats::enabled() {
+        return Err(alloc_stats::DISABLED.into());
+    }
+    let mut args = args.iter();
+    let mut days = Vec::new();
+    let mut part: Option<Part> = None;
+    let mut input: Option<PathBuf> = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => part = Some(args.next().ok_or(USAGE)?.parse()?),
+            "--input" => input = Some(PathBuf::from(args.next().ok_or(USAGE)?)),
+            _ => days.push(parse_day(arg)?),
+        }
+    }
+    if days.is_empty() {
+        days = (1..=runner::LAST_DAY).collect();
+    }
+    if input.is_some() && days.len() != 1 {
+        return Err("--input needs exactly one day.".into());
+    }
+    for day in days {
+        let path = input.clone().unwrap_or_else(|| runner::default_input(day));
+        let lines = runner::read_input(&path)?;
+        println!("{}", alloc_stats::alloc_day(day, &lines, part).map_err(|e| e.in_file(&path))?);
+    }
+    Ok(())
+}
+
+fn run_readme(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut args = args.iter();
+    let mut days = Vec::new();
+    let mut path = PathBuf::from(readme::DEFAULT_README);
+    let mut machine: Option<String> = None;
+    let mut runs = bench::DEFAULT_RUNS;
+    let mut warmup = bench::DEFAULT_WARMUP;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--readme" => path = PathBuf::from(args.next().ok_or(USAGE)?),
+            "--machine" => machine = Some(args.next().ok_or(USAGE)?.clone()),
+            "--runs" => runs = args.next().ok_or(USAGE)?.parse()?,
+            "--warmup" => warmup = args.next().ok_or(USAGE)?.parse()?,
+            _ => days.push(parse_day(arg)?),
+        }
+    }
+    if days.is_empty() {
+        days = (1..=runner::LAST_DAY).collect();
+    }
+    let machine = match machine {
+        Some(machine) => machine,
+        None => readme::host_machine()?,
+    };
+    let mut sections = Vec::new();
+    for day in days {
+        let lines = runner::read_input(&runner::default_input(day))?;
+        let day_bench = bench::bench_day(day, &lines, None, warmup, runs)?;
+        println!("{}", day_bench);
+        sections.push((day, readme::machine_section(&machine, &day_bench)));
+    }
+    readme::write_sections(&path, &machine, &sections)?;
+    println!("Updated {} for {}.", path.display(), machine);
+    Ok(())
+}
+
+fn run_generate(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut args = args.iter();
+    let day = parse_day(args.next().ok_or(USAGE)?)?;
+    let mut options = GenOptions::default();
+    let mut output: Option<PathBuf> = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" => options.size = Some(args.next().ok_or(USAGE)?.parse()?),
+            "--seed" => options.seed = args.next().ok_or(USAGE)?.parse()?,
+            "--density" => options.density = Some(args.next().ok_or(USAGE)?.parse()?),
+            "--frequencies" => options.frequencies = Some(args.next().ok_or(USAGE)?.parse()?),
+            "--output" => output = Some(PathBuf::from(args.next().ok_or(USAGE)?)),
+            _ => return Err(format!("Unknown argument '{}'.\n{}", arg, USAGE).into()),
+        }
+    }
+    let mut text = generate::generate(day, &options)?.join("\n");
+    text.push('\n');
+    match output {
+        Some(path) => {
+            fs::write(&path, text).map_err(|e| format!("Couldn't write {}: {}", path.display(), e))?;
+            let unit = generate::size_unit(day).unwrap_or("");
+            let size = options.size.map_or("the default number of".to_string(), |size| size.to_string());
+            println!("Wrote day {} with {} {} to {}.", day, size, unit, path.display());
+        },
+        None => print!("{}", text),
+    }
+    Ok(())
+}
+
+fn run_step(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut args = args.iter();
+    let mut input = runner::default_input(6);
+    let mut view = stepper::DEFAULT_VIEW;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => input = PathBuf::from(args.next().ok_or(USAGE)?),
+            "--view" => {
+                let arg = args.next().ok_or(USAGE)?;
+                let (width, height) = arg.split_once('x').ok_or_else(|| format!("--view takes <width>x<height>, not '{}'.", arg))?;
+                view = (width.parse()?, height.parse()?);
+            },
+            _ => return Err(format!("Unknown argument '{}'.\n{}", arg, USAGE).into()),
+        }
+    }
+    if input.as_os_str() == "-" {
+        return Err("The stepper reads its commands from stdin, so the map has to come from a file.".into());
+    }
+    let lines = runner::read_input(&input)?;
+    let mut stepper = Stepper::new(&lines).map_err(|e| e.in_file(&input))?;
+    let stdout = io::stdout();
+    let clear = stdout.is_terminal();
+    stepper::interact(&mut stepper, io::stdin().lock(), &mut stdout.lock(), view, clear)?;
+    Ok(())
 }
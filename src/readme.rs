@@ -0,0 +1,266 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+use crate::bench::DayBench;
+
+pub const DEFAULT_README: &str = "README.md";
+
+/// The `#### <CPU>, <N> GB` heading for this machine, e.g. `Apple M3 Pro, 18 GB`.
+pub fn host_machine() -> Result<String, Box<dyn Error>> {
+    let (cpu, bytes) = if cfg!(target_os = "macos") {
+        let cpu = sysctl("machdep.cpu.brand_string")?;
+        let bytes = sysctl("hw.memsize")?.parse::<u64>()?;
+        (cpu, bytes)
+    } else {
+        let cpuinfo = fs::read_to_string("/proc/cpuinfo")?;
+        let meminfo = fs::read_to_string("/proc/meminfo")?;
+        let cpu = cpu_model(&cpuinfo).ok_or("No CPU model in /proc/cpuinfo.")?;
+        let bytes = mem_total(&meminfo).ok_or("No MemTotal in /proc/meminfo.")?;
+        (cpu, bytes)
+    };
+    Ok(format!("{}, {} GB", cpu, gigabytes(bytes)))
+}
+
+fn sysctl(name: &str) -> Result<String, Box<dyn Error>> {
+    let output = Command::new("sysctl").arg("-n").arg(name).output()?;
+    if !output.status.success() {
+        return Err(format!("sysctl {} failed.", name).into());
+    }
+    Ok(String::from_utf8(output.stdout)?.trim().to_string())
+}
+
+/// The first `model name` in `/proc/cpuinfo`, with runs of spaces collapsed.
+fn cpu_model(cpuinfo: &str) -> Option<String> {
+    cpuinfo.lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(key, _)| key.trim() == "model name")
+        .map(|(_, value)| value.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+/// `MemTotal` from `/proc/meminfo`, in bytes.
+fn mem_total(meminfo: &str) -> Option<u64> {
+    let line = meminfo.lines().find(|line| line.starts_with("MemTotal:"))?;
+    let kb = line["MemTotal:".len()..].trim().trim_end_matches("kB").trim();
+    kb.parse::<u64>().ok().map(|kb| kb * 1024)
+}
+
+/// Rounds up to whole GiB, since Linux reports a little under the installed memory.
+fn gigabytes(bytes: u64) -> u64 {
+    bytes.div_ceil(1 << 30)
+}
+
+/// The machine's subsection of a day's benchmark, in the format of the hand-written ones.
+pub fn machine_section(machine: &str, bench: &DayBench) -> String {
+    let total = [Some(bench.parse), bench.part_1, bench.part_2].iter()
+        .flatten()
+        .map(|stats| stats.mean)
+        .sum::<Duration>();
+    format!("#### {}\n`Completed in: {:?}`\n```\nBenchmark: cargo run --release -- bench {}\n{}```\n",
+            machine, total, bench.day, bench)
+}
+
+fn heading_level(line: &str) -> Option<usize> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    (level > 0 && line[level..].starts_with(' ')).then_some(level)
+}
+
+/// The index of the first heading at `level` or above at or after `from`, or the end of `lines`.
+fn section_end(lines: &[String], from: usize, level: usize) -> usize {
+    (from..lines.len())
+        .find(|&i| heading_level(&lines[i]).is_some_and(|l| l <= level))
+        .unwrap_or(lines.len())
+}
+
+/// Backs up over blank lines so inserted text lands before a section's trailing whitespace.
+fn before_blanks(lines: &[String], from: usize, mut end: usize) -> usize {
+    while end > from && lines[end - 1].trim().is_empty() {
+        end -= 1;
+    }
+    end
+}
+
+fn to_lines(text: &str) -> Vec<String> {
+    text.lines().map(|x| x.to_string()).collect()
+}
+
+/// Replaces `machine`'s subsection under "## Day N" / "### Benchmark", or adds it after the other machines.
+/// Creates the day section and its benchmark heading when they're missing. Everything else is left as it was.
+pub fn update_day(readme: &str, day: u8, machine: &str, section: &str) -> String {
+    let mut lines = to_lines(readme);
+    let day_heading = format!("## Day {}", day);
+    let day_start = match lines.iter().position(|line| line.trim_end() == day_heading) {
+        Some(start) => start,
+        None => {
+            let later = lines.iter().position(|line| {
+                line.strip_prefix("## Day ")
+                    .and_then(|n| n.trim().parse::<u8>().ok())
+                    .is_some_and(|n| n > day)
+            });
+            let at = before_blanks(&lines, 0, later.unwrap_or(lines.len()));
+            lines.splice(at..at, [
+                String::new(),
+                day_heading,
+                format!("[Problem Text](https://adventofcode.com/2024/day/{})", day),
+            ]);
+            at + 1
+        }
+    };
+    let day_end = section_end(&lines, day_start + 1, 2);
+
+    let bench_start = match (day_start..day_end).find(|&i| lines[i].trim_end() == "### Benchmark") {
+        Some(start) => start,
+        None => {
+            let at = before_blanks(&lines, day_start, day_end);
+            lines.splice(at..at, [String::new(), "### Benchmark".to_string()]);
+            at + 1
+        }
+    };
+    let day_end = section_end(&lines, bench_start + 1, 2);
+
+    let machine_heading = format!("#### {}", machine);
+    let section = to_lines(section);
+    match (bench_start..day_end).find(|&i| lines[i].trim_end() == machine_heading) {
+        Some(start) => {
+            let end = section_end(&lines, start + 1, 4);
+            let end = before_blanks(&lines, start, end);
+            lines.splice(start..end, section);
+        }
+        None => {
+            let at = before_blanks(&lines, bench_start, day_end);
+            let mut new = section;
+            new.insert(0, String::new());
+            lines.splice(at..at, new);
+        }
+    }
+
+    let mut updated = lines.join("\n");
+    if readme.ends_with('\n') {
+        updated.push('\n');
+    }
+    updated
+}
+
+/// Adds an empty "## Day N" section with a blank benchmark for every machine the README already lists,
+/// like the ones written ahead of time for days 14 to 16. `None` when the day already has a section.
+pub fn placeholder_day(readme: &str, day: u8) -> Option<String> {
+    let day_heading = format!("## Day {}", day);
+    if readme.lines().any(|line| line.trim_end() == day_heading) {
+        return None;
+    }
+    let mut machines: Vec<&str> = Vec::new();
+    for machine in readme.lines().filter_map(|line| line.strip_prefix("#### ")) {
+        if !machines.contains(&machine.trim_end()) {
+            machines.push(machine.trim_end());
+        }
+    }
+    let updated = machines.iter().fold(readme.to_string(), |readme, machine| {
+        update_day(&readme, day, machine, &format!("#### {}\n``\n```\n```\n", machine))
+    });
+    Some(updated)
+}
+
+/// Writes `sections` (day and subsection text) for `machine` into the README at `path`.
+pub fn write_sections(path: &Path, machine: &str, sections: &[(u8, String)]) -> Result<(), Box<dyn Error>> {
+    let mut readme = fs::read_to_string(path)
+        .map_err(|e| format!("Couldn't read {}: {}", path.display(), e))?;
+    for (day, section) in sections {
+        readme = update_day(&readme, *day, machine, section);
+    }
+    fs::write(path, readme)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cpu_model, gigabytes, mem_total, placeholder_day, update_day};
+
+    const README: &str = "# Advent of Code 2024
+
+## Day 1
+
+[Problem Text](https://adventofcode.com/2024/day/1)
+
+### Benchmark
+
+#### Apple M3 Pro, 18 GB
+`Completed in: 797.25µs`
+```
+old
+```
+
+#### Apple M2 Pro, 16 GB
+`Completed in: 1.054959ms`
+```
+old
+```
+
+## Day 7
+[Problem Text](https://adventofcode.com/2024/day/7)
+
+### Benchmark
+
+#### Apple M3 Pro, 18 GB
+
+##### With Recursion
+`Completed in: 483.940916ms`
+```
+old
+```
+";
+
+    const SECTION: &str = "#### Apple M3 Pro, 18 GB\n`Completed in: 1ms`\n```\nnew\n```\n";
+
+    #[test]
+    fn test_host_parsing() {
+        let cpuinfo = "processor\t: 0\nmodel name\t: AMD Ryzen 7  5800X 8-Core Processor\ncpu MHz\t\t: 3800.000\n";
+        assert_eq!(cpu_model(cpuinfo), Some("AMD Ryzen 7 5800X 8-Core Processor".to_string()));
+        assert_eq!(cpu_model("processor\t: 0\n"), None);
+        let meminfo = "MemTotal:       32768000 kB\nMemFree:        1000 kB\n";
+        assert_eq!(mem_total(meminfo), Some(32768000 * 1024));
+        // 31.25 GiB of usable memory on a 32 GB machine.
+        assert_eq!(gigabytes(32768000 * 1024), 32);
+        assert_eq!(gigabytes(18 << 30), 18);
+    }
+
+    #[test]
+    fn test_replace_machine() {
+        let updated = update_day(README, 1, "Apple M3 Pro, 18 GB", SECTION);
+        assert!(updated.contains("#### Apple M3 Pro, 18 GB\n`Completed in: 1ms`\n```\nnew\n```\n\n#### Apple M2 Pro, 16 GB\n`Completed in: 1.054959ms`"));
+        assert!(!updated.contains("797.25µs"));
+        // Day 7's M3 entry, with its nested headings, is untouched.
+        assert!(updated.contains("##### With Recursion\n`Completed in: 483.940916ms`"));
+    }
+
+    #[test]
+    fn test_nested_headings_replaced() {
+        let updated = update_day(README, 7, "Apple M3 Pro, 18 GB", SECTION);
+        assert!(!updated.contains("With Recursion"));
+        assert!(updated.ends_with("### Benchmark\n\n#### Apple M3 Pro, 18 GB\n`Completed in: 1ms`\n```\nnew\n```\n"));
+    }
+
+    #[test]
+    fn test_add_machine() {
+        let section = "#### AMD Ryzen 7 5800X 8-Core Processor, 32 GB\n`Completed in: 2ms`\n```\nnew\n```\n";
+        let updated = update_day(README, 1, "AMD Ryzen 7 5800X 8-Core Processor, 32 GB", section);
+        assert!(updated.contains("`Completed in: 1.054959ms`\n```\nold\n```\n\n#### AMD Ryzen 7 5800X 8-Core Processor, 32 GB\n`Completed in: 2ms`\n```\nnew\n```\n\n## Day 7"));
+        assert!(updated.contains("797.25µs"));
+    }
+
+    #[test]
+    fn test_add_day() {
+        let updated = update_day(README, 3, "Apple M3 Pro, 18 GB", SECTION);
+        assert!(updated.contains("```\n\n## Day 3\n[Problem Text](https://adventofcode.com/2024/day/3)\n\n### Benchmark\n\n#### Apple M3 Pro, 18 GB\n`Completed in: 1ms`\n```\nnew\n```\n\n## Day 7\n"));
+
+        let updated = update_day(README, 9, "Apple M3 Pro, 18 GB", SECTION);
+        assert!(updated.ends_with("```\n\n## Day 9\n[Problem Text](https://adventofcode.com/2024/day/9)\n\n### Benchmark\n\n#### Apple M3 Pro, 18 GB\n`Completed in: 1ms`\n```\nnew\n```\n"));
+    }
+
+    #[test]
+    fn test_placeholder_day() {
+        assert_eq!(placeholder_day(README, 7), None);
+        let updated = placeholder_day(README, 14).unwrap();
+        assert!(updated.ends_with("```\n\n## Day 14\n[Problem Text](https://adventofcode.com/2024/day/14)\n\n### Benchmark\n\n#### Apple M3 Pro, 18 GB\n``\n```\n```\n\n#### Apple M2 Pro, 16 GB\n``\n```\n```\n"), "{}", updated);
+    }
+}
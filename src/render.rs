@@ -0,0 +1,177 @@
+use std::fs;
+use std::path::Path;
+use std::time::Instant;
+use crate::days::{day_06, day_08, day_10, day_12, Day06, Day08, Day10, Day12};
+use crate::runner::{self, Answers, Part};
+use crate::util::{png, span};
+use crate::{AocError, Solution};
+
+pub type Rgb = [u8; 3];
+
+pub const BACKGROUND: Rgb = [24, 24, 32];
+
+/// The days `solve_and_render` can draw.
+pub const RENDERED_DAYS: [u8; 4] = [6, 8, 10, 12];
+
+/// About how many pixels across the longer side of a picture should be, so small grids don't come out tiny.
+const TARGET_PIXELS: usize = 800;
+
+/// A grid of colored cells, each drawn as a square of pixels when saved.
+pub struct Canvas {
+    width: usize,
+    height: usize,
+    cells: Vec<Rgb>,
+}
+
+impl Canvas {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Self { width, height, cells: vec![background; width * height] }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, color: Rgb) {
+        self.cells[y * self.width + x] = color;
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Rgb {
+        self.cells[y * self.width + x]
+    }
+
+    /// Pixels per cell for a picture about `TARGET_PIXELS` across.
+    pub fn scale(&self) -> usize {
+        (TARGET_PIXELS / self.width.max(self.height).max(1)).clamp(1, 32)
+    }
+
+    pub fn to_png(&self, scale: usize) -> Vec<u8> {
+        let (width, height) = (self.width * scale, self.height * scale);
+        let mut rgb = Vec::with_capacity(width * height * 3);
+        for y in 0..height {
+            for x in 0..width {
+                rgb.extend_from_slice(&self.get(x / scale, y / scale));
+            }
+        }
+        png::encode(width as u32, height as u32, &rgb)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        fs::write(path, self.to_png(self.scale()))
+            .map_err(|e| AocError::new(format!("Couldn't write {}: {}", path.display(), e)))
+    }
+}
+
+/// A color for the `idx`th thing of a kind, stepping around the hue circle so neighbours in the sequence stay apart.
+pub fn palette(idx: usize) -> Rgb {
+    let hue = (idx as f64 * 0.618_033_988_75).fract() * 6.0;
+    let (saturation, value) = (0.65, 0.95);
+    let chroma = value * saturation;
+    let second = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+    let (r, g, b) = match hue as usize {
+        0 => (chroma, second, 0.0),
+        1 => (second, chroma, 0.0),
+        2 => (0.0, chroma, second),
+        3 => (0.0, second, chroma),
+        4 => (second, 0.0, chroma),
+        _ => (chroma, 0.0, second),
+    };
+    let lift = value - chroma;
+    [r, g, b].map(|channel| ((channel + lift) * 255.0).round() as u8)
+}
+
+/// `t` of the way from `from` to `to`.
+pub fn mix(from: Rgb, to: Rgb, t: f64) -> Rgb {
+    let t = t.clamp(0.0, 1.0);
+    [0, 1, 2].map(|i| (from[i] as f64 + (to[i] as f64 - from[i] as f64) * t).round() as u8)
+}
+
+pub fn nothing_to_render(day: u8) -> AocError {
+    let days: Vec<String> = RENDERED_DAYS.iter().map(|day| day.to_string()).collect();
+    AocError::new(format!("Day {} has nothing to render; days {} do.", day, days.join(", ")))
+}
+
+/// Answers `part` of `day`, or both parts, like `runner::solve`, and draws the same parsed input.
+/// When drawing works out part 2's answer anyway, as it does for day 6, that answer is used rather than
+/// solving part 2 again, and part 2's time is how long drawing took.
+pub fn solve_and_render(day: u8, lines: &Vec<String>, part: Option<Part>) -> Result<(Answers, Canvas), AocError> {
+    match day {
+        6 => solve_drawing::<Day06>(lines, part, |map| {
+            let (canvas, obstacles) = day_06::render(map);
+            (canvas, Some(obstacles))
+        }),
+        8 => solve_drawing::<Day08>(lines, part, |input| (day_08::render(input), None)),
+        10 => solve_drawing::<Day10>(lines, part, |input| (day_10::render(input), None)),
+        12 => solve_drawing::<Day12>(lines, part, |garden| (day_12::render(garden), None)),
+        _ => Err(nothing_to_render(day)),
+    }
+}
+
+fn solve_drawing<S: Solution>(lines: &Vec<String>, part: Option<Part>, draw: impl FnOnce(&S::Input) -> (Canvas, Option<S::Answer>)) -> Result<(Answers, Canvas), AocError> {
+    let (input, mut answers) = runner::parse_with::<S>(lines)?;
+    if part != Some(Part::Two) {
+        runner::answer_with::<S>(&input, Part::One, &mut answers);
+    }
+    let start = Instant::now();
+    let (canvas, part_2) = {
+        let _span = span::enter("render");
+        draw(&input)
+    };
+    if part != Some(Part::One) {
+        match part_2 {
+            Some(answer) => {
+                answers.part_2 = Some(answer.to_string());
+                answers.part_2_time = Some(start.elapsed());
+            },
+            None => runner::answer_with::<S>(&input, Part::Two, &mut answers),
+        }
+    }
+    Ok((answers, canvas))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mix, palette, solve_and_render, Canvas, BACKGROUND};
+    use crate::runner::Part;
+    use crate::util::parsing;
+
+    #[test]
+    fn test_canvas() {
+        let mut canvas = Canvas::new(3, 2, BACKGROUND);
+        canvas.set(2, 1, [255, 0, 0]);
+        assert_eq!(canvas.get(2, 1), [255, 0, 0]);
+        assert_eq!(canvas.get(1, 1), BACKGROUND);
+        assert_eq!(canvas.scale(), 32);
+        let png = canvas.to_png(4);
+        assert_eq!(&png[16..24], &[0, 0, 0, 12, 0, 0, 0, 8]);
+
+        assert_ne!(palette(0), palette(1));
+        assert_eq!(mix([0, 0, 0], [200, 100, 50], 0.5), [100, 50, 25]);
+    }
+
+    #[test]
+    fn test_solve_and_render() {
+        let lines = parsing::file_into_vec("files/day_06_example.txt").unwrap();
+        let (answers, canvas) = solve_and_render(6, &lines, None).unwrap();
+        assert_eq!((canvas.width(), canvas.height()), (10, 10));
+        assert_eq!(answers.part_1.as_deref(), Some("41"));
+        assert_eq!(answers.part_2.as_deref(), Some("6"));
+        let (answers, _) = solve_and_render(6, &lines, Some(Part::One)).unwrap();
+        assert_eq!(answers.part_2, None);
+        assert!(solve_and_render(7, &lines, None).is_err());
+
+        let lines = parsing::file_into_vec("files/day_12_tiny.txt").unwrap();
+        let (answers, canvas) = solve_and_render(12, &lines, None).unwrap();
+        assert_eq!(answers.part_2.as_deref(), Some("80"));
+        let mut colors: Vec<_> = (0..16).map(|i| canvas.get(i % 4, i / 4)).collect();
+        assert_eq!(colors[4], colors[9]);
+        assert_ne!(colors[2], colors[7]);
+        colors.sort();
+        colors.dedup();
+        assert_eq!(colors.len(), 5);
+    }
+}
@@ -0,0 +1,168 @@
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::runner::{self, Answers};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Status {
+    Ok,
+    Panic(String),
+    Error(String),
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Panic(_) => write!(f, "panic"),
+            Status::Error(_) => write!(f, "error"),
+        }
+    }
+}
+
+pub struct DayResult {
+    pub day: u8,
+    pub answers: Answers,
+    pub status: Status,
+}
+
+/// Solves both parts of one day on its real input, catching a panic instead of unwinding.
+pub fn run_day(day: u8) -> DayResult {
+    let path = runner::default_input(day);
+    let result = runner::read_input(&path)
+        .map_err(|e| Status::Error(e.to_string()))
+        .and_then(|lines| match runner::catch_panic(|| runner::solve(day, &lines, None)) {
+            Ok(Ok(answers)) => Ok(answers),
+            Ok(Err(e)) => Err(Status::Error(e.in_file(&path).to_string())),
+            Err(message) => Err(Status::Panic(message)),
+        });
+    match result {
+        Ok(answers) => DayResult { day, answers, status: Status::Ok },
+        Err(status) => DayResult { day, answers: Answers::default(), status },
+    }
+}
+
+/// Runs `days` on up to `threads` threads and returns the results in the order of `days`.
+pub fn run_days(days: &[u8], threads: usize) -> Vec<DayResult> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(days.len()));
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, days.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(&day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let result = run_day(day);
+                    results.lock().unwrap().push(result);
+                }
+            });
+        }
+    });
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|result| days.iter().position(|&day| day == result.day));
+    results
+}
+
+fn time(duration: Option<Duration>) -> String {
+    duration.map(|d| format!("{:.2?}", d)).unwrap_or_else(|| "-".to_string())
+}
+
+/// The results as an aligned table, then any failure messages and the total time.
+/// `wall` is how long the whole run took, which is less than the sum with more than one thread.
+pub fn summary(results: &[DayResult], wall: Duration) -> String {
+    let header = ["day", "part 1", "part 2", "parse", "part 1", "part 2", "status"].map(String::from);
+    let rows: Vec<[String; 7]> = results.iter().map(|result| {
+        let answers = &result.answers;
+        let failed = result.status != Status::Ok;
+        [
+            result.day.to_string(),
+            answers.part_1.clone().unwrap_or_else(|| "-".to_string()),
+            answers.part_2.clone().unwrap_or_else(|| "-".to_string()),
+            if failed { "-".to_string() } else { time(Some(answers.parse_time)) },
+            time(answers.part_1_time),
+            time(answers.part_2_time),
+            result.status.to_string(),
+        ]
+    }).collect();
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| rows.iter().chain([&header]).map(|row| row[i].chars().count()).max().unwrap_or(0))
+        .collect();
+    let line = |row: &[String; 7]| {
+        let cells: Vec<String> = row.iter().zip(&widths).enumerate().map(|(i, (cell, &width))| {
+            // Text on the left, numbers and times on the right.
+            if i == 0 || i == 6 { format!("{:<width$}", cell) } else { format!("{:>width$}", cell) }
+        }).collect();
+        cells.join("  ").trim_end().to_string()
+    };
+
+    let mut out = String::new();
+    out.push_str(&line(&header));
+    out.push('\n');
+    for row in &rows {
+        out.push_str(&line(row));
+        out.push('\n');
+    }
+    for result in results {
+        if let Status::Panic(message) | Status::Error(message) = &result.status {
+            out.push_str(&format!("Day {} {}: {}\n", result.day, result.status, message));
+        }
+    }
+    let solving: Duration = results.iter()
+        .map(|result| result.answers.parse_time + result.answers.part_1_time.unwrap_or_default() + result.answers.part_2_time.unwrap_or_default())
+        .sum();
+    let failures = results.iter().filter(|result| result.status != Status::Ok).count();
+    out.push_str(&format!("Total: {:.2?} ({:.2?} solving), {} of {} days ok", wall, solving, results.len() - failures, results.len()));
+    out
+}
+
+/// Runs every day in `days`, prints the table and returns whether they all finished.
+pub fn run_all(days: &[u8], threads: usize) -> bool {
+    let start = Instant::now();
+    let results = run_days(days, threads);
+    println!("{}", summary(&results, start.elapsed()));
+    results.iter().all(|result| result.status == Status::Ok)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::{run_days, summary, DayResult, Status};
+    use crate::runner::Answers;
+
+    #[test]
+    fn test_summary() {
+        let results = vec![
+            DayResult {
+                day: 9,
+                answers: Answers {
+                    part_1: Some("1928".to_string()),
+                    part_2: Some("2858".to_string()),
+                    parse_time: Duration::from_micros(5),
+                    part_1_time: Some(Duration::from_micros(20)),
+                    part_2_time: Some(Duration::from_millis(3)),
+                },
+                status: Status::Ok,
+            },
+            DayResult {
+                day: 10,
+                answers: Answers::default(),
+                status: Status::Panic("Can't handle multiple guards!".to_string()),
+            },
+        ];
+        let table = summary(&results, Duration::from_millis(4));
+        assert_eq!(table, "\
+day  part 1  part 2   parse   part 1  part 2  status
+9      1928    2858  5.00µs  20.00µs  3.00ms  ok
+10        -       -       -        -       -  panic
+Day 10 panic: Can't handle multiple guards!
+Total: 4.00ms (3.02ms solving), 1 of 2 days ok");
+    }
+
+    #[test]
+    fn test_run_days() {
+        // Day 25 has no solution, so it comes back as an error without stopping the others.
+        let results = run_days(&[25, 1, 11], 2);
+        assert_eq!(results.iter().map(|result| result.day).collect::<Vec<_>>(), vec![25, 1, 11]);
+        assert!(matches!(results[0].status, Status::Error(_)));
+    }
+}
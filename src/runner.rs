@@ -0,0 +1,363 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use crate::{render, AocError, Solution};
+use crate::util::parsing::{self, LoadOptions};
+use crate::util::span;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Part must be 1 or 2, not '{}'.", s)),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// How `run` prints its results.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Format {
+    /// Each day's own labels, then the total time.
+    #[default]
+    Text,
+    /// One JSON object per part, one per line.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Format must be text or json, not '{}'.", s)),
+        }
+    }
+}
+
+pub struct RunOptions {
+    pub day: u8,
+    /// Run only this part, or both when `None`.
+    pub part: Option<Part>,
+    /// A file, or `-` for stdin.
+    pub input: PathBuf,
+    pub load: LoadOptions,
+    pub format: Format,
+    /// Print the tree of `util::span` timings after the answers.
+    pub spans: bool,
+    /// Instead of answering once, loop the part for this long; see `profile`.
+    pub profile: Option<Duration>,
+    /// Also draw the input and its answers to this PNG; see `render::solve_and_render`.
+    pub render: Option<PathBuf>,
+}
+
+impl RunOptions {
+    pub fn new(day: u8) -> Self {
+        Self {
+            day,
+            part: None,
+            input: default_input(day),
+            load: LoadOptions::default(),
+            format: Format::default(),
+            spans: false,
+            profile: None,
+            render: None,
+        }
+    }
+}
+
+/// The last day with a solution.
+pub const LAST_DAY: u8 = 13;
+
+pub fn default_input(day: u8) -> PathBuf {
+    PathBuf::from(format!("files/day_{:02}_input.txt", day))
+}
+
+/// Calls `$f::<DayNN>(args)` for the given day, or evaluates to `None` for a day without a solution.
+macro_rules! dispatch {
+    ($day:expr, $f:ident($($arg:expr),*)) => {
+        match $day {
+            1 => Some($f::<$crate::days::Day01>($($arg),*)),
+            2 => Some($f::<$crate::days::Day02>($($arg),*)),
+            3 => Some($f::<$crate::days::Day03>($($arg),*)),
+            4 => Some($f::<$crate::days::Day04>($($arg),*)),
+            5 => Some($f::<$crate::days::Day05>($($arg),*)),
+            6 => Some($f::<$crate::days::Day06>($($arg),*)),
+            7 => Some($f::<$crate::days::Day07>($($arg),*)),
+            8 => Some($f::<$crate::days::Day08>($($arg),*)),
+            9 => Some($f::<$crate::days::Day09>($($arg),*)),
+            10 => Some($f::<$crate::days::Day10>($($arg),*)),
+            11 => Some($f::<$crate::days::Day11>($($arg),*)),
+            12 => Some($f::<$crate::days::Day12>($($arg),*)),
+            13 => Some($f::<$crate::days::Day13>($($arg),*)),
+            _ => None,
+        }
+    };
+}
+pub(crate) use dispatch;
+
+/// The answers from one run of a day, formatted as they are printed, and how long each phase took.
+#[derive(Default, Debug)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+    pub parse_time: Duration,
+    pub part_1_time: Option<Duration>,
+    pub part_2_time: Option<Duration>,
+}
+
+impl Answers {
+    pub fn get(&self, part: Part) -> Option<&String> {
+        match part {
+            Part::One => self.part_1.as_ref(),
+            Part::Two => self.part_2.as_ref(),
+        }
+    }
+
+    pub fn time(&self, part: Part) -> Option<Duration> {
+        match part {
+            Part::One => self.part_1_time,
+            Part::Two => self.part_2_time,
+        }
+    }
+}
+
+/// Parses the input, with `Answers` holding how long that took and nothing else yet.
+pub(crate) fn parse_with<S: Solution>(lines: &Vec<String>) -> Result<(S::Input, Answers), AocError> {
+    let start = Instant::now();
+    let input = {
+        let _span = span::enter("parse");
+        S::parse(lines)?
+    };
+    let answers = Answers {
+        parse_time: start.elapsed(),
+        ..Answers::default()
+    };
+    Ok((input, answers))
+}
+
+/// Answers one part, recording the answer and how long it took in `answers`.
+pub(crate) fn answer_with<S: Solution>(input: &S::Input, part: Part, answers: &mut Answers) {
+    let start = Instant::now();
+    match part {
+        Part::One => {
+            let _span = span::enter("part 1");
+            answers.part_1 = Some(S::part1(input).to_string());
+            answers.part_1_time = Some(start.elapsed());
+        },
+        Part::Two => {
+            let _span = span::enter("part 2");
+            answers.part_2 = Some(S::part2(input).to_string());
+            answers.part_2_time = Some(start.elapsed());
+        },
+    }
+}
+
+fn solve_with<S: Solution>(lines: &Vec<String>, part: Option<Part>) -> Result<Answers, AocError> {
+    let (input, mut answers) = parse_with::<S>(lines)?;
+    if part != Some(Part::Two) {
+        answer_with::<S>(&input, Part::One, &mut answers);
+    }
+    if part != Some(Part::One) {
+        answer_with::<S>(&input, Part::Two, &mut answers);
+    }
+    Ok(answers)
+}
+
+/// How many times `profile` ran a part, and for how long altogether.
+pub struct Profile {
+    pub iterations: usize,
+    pub elapsed: Duration,
+}
+
+fn profile_with<S: Solution>(lines: &Vec<String>, part: Part, length: Duration) -> Result<Profile, AocError> {
+    let input = S::parse(lines)?;
+    let start = Instant::now();
+    let mut iterations = 0;
+    while iterations == 0 || start.elapsed() < length {
+        let _span = span::enter(if part == Part::One { "part 1" } else { "part 2" });
+        match part {
+            Part::One => black_box(S::part1(black_box(&input)).to_string()),
+            Part::Two => black_box(S::part2(black_box(&input)).to_string()),
+        };
+        iterations += 1;
+    }
+    Ok(Profile {
+        iterations,
+        elapsed: start.elapsed(),
+    })
+}
+
+/// Parses once, then runs `part` over and over for at least `length`,
+/// so a sampling profiler attached to the process sees mostly that part.
+pub fn profile(day: u8, lines: &Vec<String>, part: Part, length: Duration) -> Result<Profile, AocError> {
+    dispatch!(day, profile_with(lines, part, length)).ok_or_else(|| no_solution(day))?
+}
+
+fn labels_of<S: Solution>() -> (&'static str, &'static str) {
+    (S::PART_1, S::PART_2)
+}
+
+pub(crate) fn no_solution(day: u8) -> AocError {
+    AocError::new(format!("Day {} has no solution yet.", day))
+}
+
+/// Parses the input and answers the requested part, or both when `part` is `None`.
+pub fn solve(day: u8, lines: &Vec<String>, part: Option<Part>) -> Result<Answers, AocError> {
+    dispatch!(day, solve_with(lines, part)).ok_or_else(|| no_solution(day))?
+}
+
+/// Reads a day's input, or stdin when `path` is `-`.
+pub fn read_input(path: &Path) -> Result<Vec<String>, AocError> {
+    read_input_with(path, LoadOptions::default())
+}
+
+pub fn read_input_with(path: &Path, options: LoadOptions) -> Result<Vec<String>, AocError> {
+    parsing::load_lines(path, options).map_err(|e| {
+        let message = format!("Couldn't read input: {}", e.message);
+        AocError { message, ..e }.in_file(source_name(path))
+    })
+}
+
+/// What to call the input in errors, since `-` on its own reads oddly.
+fn source_name(path: &Path) -> &Path {
+    if path == Path::new("-") { Path::new("<stdin>") } else { path }
+}
+
+/// Runs `f`, turning a panic into an `Err` holding the panic message.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "unknown panic".to_string()
+        }
+    })
+}
+
+pub fn run(options: &RunOptions) -> Result<(), Box<dyn Error>> {
+    let (part_1_label, part_2_label) = dispatch!(options.day, labels_of()).ok_or_else(|| no_solution(options.day))?;
+    if options.render.is_some() && !render::RENDERED_DAYS.contains(&options.day) {
+        return Err(render::nothing_to_render(options.day).into());
+    }
+    let start = Instant::now();
+
+    let lines = read_input_with(&options.input, options.load)?;
+    if options.spans {
+        span::enable();
+    }
+    if let Some(length) = options.profile {
+        let part = options.part.ok_or("--profile needs --part.")?;
+        let profile = profile(options.day, &lines, part, length).map_err(|e| e.in_file(source_name(&options.input)))?;
+        println!("Ran day {} part {} {} times in {:.2?}, {:.2?} each.",
+                 options.day, part, profile.iterations, profile.elapsed, profile.elapsed / profile.iterations as u32);
+        print!("{}", span::take_report());
+        return Ok(());
+    }
+    let answers = match &options.render {
+        Some(path) => {
+            let (answers, canvas) = render::solve_and_render(options.day, &lines, options.part)
+                .map_err(|e| e.in_file(source_name(&options.input)))?;
+            canvas.save(path)?;
+            eprintln!("Rendered day {} to {}.", options.day, path.display());
+            answers
+        },
+        None => solve(options.day, &lines, options.part).map_err(|e| e.in_file(source_name(&options.input)))?,
+    };
+    if options.format == Format::Json {
+        for part in [Part::One, Part::Two] {
+            if let (Some(answer), Some(time)) = (answers.get(part), answers.time(part)) {
+                println!("{}", json_line(options.day, part, answer, time, &options.input));
+            }
+        }
+        // Keep stdout to the JSON lines.
+        eprint!("{}", span::take_report());
+        return Ok(());
+    }
+    if let Some(answer) = answers.part_1 {
+        println!("{}:\n{}", part_1_label, answer);
+    }
+    if let Some(answer) = answers.part_2 {
+        println!("{}:\n{}", part_2_label, answer);
+    }
+
+    let duration = start.elapsed();
+    println!("Completed in: {:?}", duration);
+    print!("{}", span::take_report());
+
+    Ok(())
+}
+
+/// A string literal for JSON, quotes included.
+pub fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// One part's result as `{"day", "part", "answer", "duration_ns", "input_path"}`.
+/// The answer is a string because some days' answers don't fit in a JSON number.
+/// The duration covers the part alone, not reading or parsing the input.
+pub fn json_line(day: u8, part: Part, answer: &str, duration: Duration, input: &Path) -> String {
+    format!("{{\"day\":{},\"part\":{},\"answer\":{},\"duration_ns\":{},\"input_path\":{}}}",
+            day, part, json_string(answer), duration.as_nanos(), json_string(&input.display().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::time::Duration;
+    use super::{catch_panic, json_line, json_string, Part};
+
+    #[test]
+    fn test_catch_panic() {
+        assert_eq!(catch_panic(|| 7), Ok(7));
+        assert_eq!(catch_panic(|| panic!("Can't handle multiple guards!")), Err::<(), _>("Can't handle multiple guards!".to_string()));
+        let n = 3;
+        assert_eq!(catch_panic(|| panic!("{} guards", n)), Err::<(), _>("3 guards".to_string()));
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(json_string("a \"b\"\\\n\u{1}"), "\"a \\\"b\\\"\\\\\\n\\u0001\"");
+        let line = json_line(7, Part::Two, "11387", Duration::from_micros(12), Path::new("files/day_07_input.txt"));
+        assert_eq!(line, "{\"day\":7,\"part\":2,\"answer\":\"11387\",\"duration_ns\":12000,\"input_path\":\"files/day_07_input.txt\"}");
+    }
+}
@@ -0,0 +1,220 @@
+use std::error::Error;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use crate::readme;
+
+/// What `new_day` did, relative to the repository root.
+#[derive(Default, Debug)]
+pub struct Scaffold {
+    pub created: Vec<PathBuf>,
+    pub updated: Vec<PathBuf>,
+}
+
+fn solution_source(day: u8) -> String {
+    format!("use crate::{{AocError, Solution}};
+
+type Num = u64;
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    type Input = Vec<String>;
+    type Answer = Num;
+
+    const DAY: u8 = {day};
+    const PART_1: &'static str = \"Part 1 is\";
+    const PART_2: &'static str = \"Part 2 is\";
+
+    fn parse(lines: &Vec<String>) -> Result<Self::Input, AocError> {{
+        Ok(lines.clone())
+    }}
+
+    fn part1(_input: &Self::Input) -> Self::Answer {{
+        0
+    }}
+
+    fn part2(_input: &Self::Input) -> Self::Answer {{
+        0
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::Day{day:02};
+    use crate::util::parsing;
+    use crate::Solution;
+
+    #[test]
+    fn test_example() {{
+        let lines = parsing::file_into_vec(\"files/day_{day:02}_example.txt\").unwrap();
+        let input = Day{day:02}::parse(&lines).unwrap();
+        assert_eq!(Day{day:02}::part1(&input), 0);
+    }}
+}}
+")
+}
+
+fn bin_source(day: u8) -> String {
+    format!("use std::error::Error;
+use advent_2024::runner::{{self, RunOptions}};
+
+fn main() -> Result<(), Box<dyn Error>> {{
+    runner::run(&RunOptions::new({day}))
+}}
+")
+}
+
+/// Puts `line_for(day)` after the line of the closest earlier day, so the lists stay in order.
+fn insert_line(text: &str, day: u8, line_for: impl Fn(u8) -> String) -> Result<String, String> {
+    let mut lines: Vec<String> = text.lines().map(|x| x.to_string()).collect();
+    let new = line_for(day);
+    if lines.contains(&new) {
+        return Err(format!("'{}' is already there.", new.trim()));
+    }
+    let at = (1..day).rev()
+        .find_map(|earlier| lines.iter().position(|line| *line == line_for(earlier)))
+        .ok_or_else(|| format!("Found nowhere to put '{}'.", new.trim()))?;
+    lines.insert(at + 1, new);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Reads `path` under `root` and works out its new text with `f`, without writing anything yet.
+fn edited(root: &Path, path: &str, f: impl FnOnce(&str) -> Result<String, String>) -> Result<(PathBuf, String), Box<dyn Error>> {
+    let full = root.join(path);
+    let text = fs::read_to_string(&full).map_err(|e| format!("Couldn't read {}: {}", full.display(), e))?;
+    let text = f(&text).map_err(|e| format!("{}: {}", path, e))?;
+    Ok((PathBuf::from(path), text))
+}
+
+/// Creates each of `files`, or none of them: if one can't be created, the ones before it are removed again.
+fn create_all(root: &Path, files: Vec<(String, String)>) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut created = Vec::new();
+    for (path, contents) in files {
+        let full = root.join(&path);
+        let written = OpenOptions::new().write(true).create_new(true).open(&full)
+            .and_then(|mut file| file.write_all(contents.as_bytes()));
+        if let Err(e) = written {
+            for path in &created {
+                let _ = fs::remove_file(root.join(path));
+            }
+            return Err(format!("Couldn't create {}: {}", full.display(), e).into());
+        }
+        created.push(PathBuf::from(path));
+    }
+    Ok(created)
+}
+
+/// Writes the files for a new day under `root` and hooks it into `days/mod.rs` and the runner's dispatch.
+/// Every edit is worked out and every new file created before any existing file changes,
+/// so a failure leaves the tree as it was. An input that's already been fetched is kept.
+pub fn new_day(root: &Path, day: u8) -> Result<Scaffold, Box<dyn Error>> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("There's no day {}.", day).into());
+    }
+    let solution = format!("src/days/day_{:02}.rs", day);
+    let bin = format!("src/bin/day_{:02}.rs", day);
+    let example = format!("files/day_{:02}_example.txt", day);
+    let input = format!("files/day_{:02}_input.txt", day);
+    for path in [&solution, &bin, &example] {
+        if root.join(path).exists() {
+            return Err(format!("{} already exists; not overwriting it.", path).into());
+        }
+    }
+
+    let mut edits = vec![
+        edited(root, "src/days/mod.rs", |text| {
+            let text = insert_line(text, day, |d| format!("pub mod day_{:02};", d))?;
+            insert_line(&text, day, |d| format!("pub use day_{:02}::Day{:02};", d, d))
+        })?,
+        edited(root, "src/runner.rs", |text| {
+            let text = insert_line(text, day, |d| format!("            {} => Some($f::<$crate::days::Day{:02}>($($arg),*)),", d, d))?;
+            let last = text.lines()
+                .find_map(|line| line.strip_prefix("pub const LAST_DAY: u8 = "))
+                .and_then(|n| n.trim_end_matches(';').parse::<u8>().ok())
+                .ok_or("No LAST_DAY.")?;
+            Ok(if day > last {
+                text.replace(&format!("pub const LAST_DAY: u8 = {};", last), &format!("pub const LAST_DAY: u8 = {};", day))
+            } else {
+                text
+            })
+        })?,
+    ];
+    let readme_path = root.join(readme::DEFAULT_README);
+    let readme_text = fs::read_to_string(&readme_path)
+        .map_err(|e| format!("Couldn't read {}: {}", readme_path.display(), e))?;
+    if let Some(text) = readme::placeholder_day(&readme_text, day) {
+        edits.push((PathBuf::from(readme::DEFAULT_README), text));
+    }
+
+    let mut files = vec![(solution, solution_source(day)), (bin, bin_source(day)), (example, String::new())];
+    if !root.join(&input).exists() {
+        files.push((input, String::new()));
+    }
+    let mut scaffold = Scaffold {
+        created: create_all(root, files)?,
+        ..Scaffold::default()
+    };
+    for (path, text) in edits {
+        fs::write(root.join(&path), text)?;
+        scaffold.updated.push(path);
+    }
+    Ok(scaffold)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use super::{insert_line, new_day};
+    use crate::util::testing::scratch_dir;
+
+    #[test]
+    fn test_insert_line() {
+        let text = "pub mod day_01;\npub mod day_03;\n\npub use day_01::Day01;\n";
+        let line = |d: u8| format!("pub mod day_{:02};", d);
+        assert_eq!(insert_line(text, 2, line).unwrap(), "pub mod day_01;\npub mod day_02;\npub mod day_03;\n\npub use day_01::Day01;\n");
+        assert_eq!(insert_line(text, 4, line).unwrap(), "pub mod day_01;\npub mod day_03;\npub mod day_04;\n\npub use day_01::Day01;\n");
+        assert!(insert_line(text, 3, line).is_err());
+        assert!(insert_line(text, 1, line).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = scratch_dir("scaffold");
+        for dir in ["src/days", "src/bin", "files"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        fs::write(root.join("src/days/mod.rs"), "pub mod day_13;\n\npub use day_13::Day13;\n").unwrap();
+        fs::write(root.join("src/runner.rs"), "            13 => Some($f::<$crate::days::Day13>($($arg),*)),\n            _ => None,\n\npub const LAST_DAY: u8 = 13;\n").unwrap();
+        fs::write(root.join("README.md"), "## Day 13\n\n### Benchmark\n\n#### Apple M3 Pro, 18 GB\n").unwrap();
+        fs::write(root.join("files/day_14_input.txt"), "fetched").unwrap();
+
+        let scaffold = new_day(&root, 14).unwrap();
+        assert_eq!(scaffold.created, vec![
+            PathBuf::from("src/days/day_14.rs"),
+            PathBuf::from("src/bin/day_14.rs"),
+            PathBuf::from("files/day_14_example.txt"),
+        ]);
+        assert_eq!(fs::read_to_string(root.join("files/day_14_input.txt")).unwrap(), "fetched");
+        assert_eq!(fs::read_to_string(root.join("src/days/mod.rs")).unwrap(), "pub mod day_13;\npub mod day_14;\n\npub use day_13::Day13;\npub use day_14::Day14;\n");
+        let runner = fs::read_to_string(root.join("src/runner.rs")).unwrap();
+        assert!(runner.contains("Day13>($($arg),*)),\n            14 => Some($f::<$crate::days::Day14>($($arg),*)),\n            _ => None,"));
+        assert!(runner.contains("pub const LAST_DAY: u8 = 14;"));
+        assert!(fs::read_to_string(root.join("src/days/day_14.rs")).unwrap().contains("impl Solution for Day14 {"));
+        assert!(fs::read_to_string(root.join("README.md")).unwrap().contains("## Day 14\n[Problem Text](https://adventofcode.com/2024/day/14)"));
+
+        // Nothing gets overwritten the second time.
+        fs::write(root.join("src/days/day_14.rs"), "mine").unwrap();
+        assert!(new_day(&root, 14).is_err());
+        assert_eq!(fs::read_to_string(root.join("src/days/day_14.rs")).unwrap(), "mine");
+
+        // A file that can't be created leaves everything as it was.
+        let days = fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+        fs::remove_dir_all(root.join("src/bin")).unwrap();
+        assert!(new_day(&root, 15).is_err());
+        assert!(!root.join("src/days/day_15.rs").exists());
+        assert_eq!(fs::read_to_string(root.join("src/days/mod.rs")).unwrap(), days);
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,20 @@
+use std::fmt::Display;
+use crate::util::error::AocError;
+
+/// One day's puzzle: parse the input once, then answer both parts from it.
+pub trait Solution {
+    type Input;
+    type Answer: Display;
+
+    const DAY: u8;
+    /// What the answer to part 1 means, printed above it.
+    const PART_1: &'static str;
+    /// What the answer to part 2 means, printed above it.
+    const PART_2: &'static str;
+
+    fn parse(lines: &Vec<String>) -> Result<Self::Input, AocError>;
+
+    fn part1(input: &Self::Input) -> Self::Answer;
+
+    fn part2(input: &Self::Input) -> Self::Answer;
+}
@@ -0,0 +1,397 @@
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::io::{self, BufRead, Write};
+use crate::days::day_06::{count_visited_map, parse_map, step_map, Cell, Guard, Map};
+use crate::util::geom::Dir4;
+use crate::AocError;
+
+pub const HELP: &str = "Commands, each followed by Enter:
+    s [<n>]      step the guard forward once, or n times (Enter alone steps once too)
+    b [<n>]      step back once, or n times
+    t            run to the guard's next turn
+    r            run until the guard leaves the map or starts to loop
+    o [<x> <y>]  place or remove an obstruction at x, y or on the focused cell
+    f [<x> <y>]  focus on x, y, or follow the guard again
+    ?            show this help
+    q            quit";
+
+/// How much of the map `render` shows when it's bigger than that.
+pub const DEFAULT_VIEW: (usize, usize) = (60, 20);
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum State {
+    Walking,
+    Left,
+    Looping,
+}
+
+/// What a step changed, so it can be taken back: the guard, and the cell they stood on.
+struct Undo {
+    guard: Guard,
+    cell: Cell,
+}
+
+/// Steps the day 6 guard through their map one move at a time, keeping every step so they can back up again.
+pub struct Stepper {
+    map: Map,
+    undo: Vec<Undo>,
+    state: State,
+    /// What each obstruction placed with `toggle_obstruction` covered, so taking it away again puts that back.
+    covered: HashMap<(usize, usize), Cell>,
+    /// The cell the status line describes, or `None` to follow the guard.
+    focus: Option<(usize, usize)>,
+}
+
+impl Stepper {
+    pub fn new(lines: &Vec<String>) -> Result<Self, AocError> {
+        Ok(Self {
+            map: parse_map(lines)?,
+            undo: Vec::new(),
+            state: State::Walking,
+            covered: HashMap::new(),
+            focus: None,
+        })
+    }
+
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    pub fn steps(&self) -> usize {
+        self.undo.len()
+    }
+
+    pub fn visited(&self) -> usize {
+        count_visited_map(&self.map)
+    }
+
+    fn guard_cell(&self) -> (usize, usize) {
+        (self.map.guard.x as usize, self.map.guard.y as usize)
+    }
+
+    /// Moves or turns the guard once. Does nothing once they've left the map or started to loop.
+    pub fn step(&mut self) -> bool {
+        if self.state != State::Walking {
+            return false;
+        }
+        let (x, y) = self.guard_cell();
+        self.undo.push(Undo {
+            guard: self.map.guard.clone(),
+            cell: self.map.cells[(x, y)].clone(),
+        });
+        self.state = match step_map(&mut self.map) {
+            (false, _) => State::Left,
+            (true, true) => State::Looping,
+            (true, false) => State::Walking,
+        };
+        true
+    }
+
+    /// Takes back the last step, putting the cell the guard stood on back the way they found it.
+    /// An obstruction placed on that cell since goes too, as the guard has to be able to stand there again.
+    pub fn back(&mut self) -> bool {
+        let Some(undo) = self.undo.pop() else {
+            return false;
+        };
+        let (x, y) = (undo.guard.x as usize, undo.guard.y as usize);
+        self.covered.remove(&(x, y));
+        self.map.cells[(x, y)] = undo.cell;
+        self.map.guard = undo.guard;
+        self.state = State::Walking;
+        true
+    }
+
+    /// Steps until the guard turns, leaves or loops. Returns how many steps that took.
+    pub fn run_to_turn(&mut self) -> usize {
+        let dir = self.map.guard.dir;
+        let mut steps = 0;
+        while self.map.guard.dir == dir && self.step() {
+            steps += 1;
+        }
+        steps
+    }
+
+    /// Steps until the guard leaves the map or starts to loop. Returns how many steps that took.
+    pub fn run(&mut self) -> usize {
+        let mut steps = 0;
+        while self.step() {
+            steps += 1;
+        }
+        steps
+    }
+
+    /// Places an obstruction at `x`, `y`, or takes one away, leaving the cell as it was before, visits and all.
+    /// Crates and the guard's own cell stay as they are.
+    pub fn toggle_obstruction(&mut self, x: usize, y: usize) -> Result<(), String> {
+        if (x, y) == self.guard_cell() {
+            return Err("The guard is standing there.".to_string());
+        }
+        let cell = self.map.cells.get_mut(x as isize, y as isize).ok_or_else(|| format!("({}, {}) isn't on the map.", x, y))?;
+        match cell {
+            Cell::Obstruction => *cell = self.covered.remove(&(x, y)).unwrap_or(Cell::Unvisited),
+            Cell::Crate => return Err(format!("({}, {}) already has a crate.", x, y)),
+            _ => {
+                let under = std::mem::replace(cell, Cell::Obstruction);
+                self.covered.insert((x, y), under);
+            },
+        }
+        Ok(())
+    }
+
+    pub fn focus(&mut self, focus: Option<(usize, usize)>) -> Result<(), String> {
+        if let Some((x, y)) = focus {
+            if !self.map.cells.contains(x as isize, y as isize) {
+                return Err(format!("({}, {}) isn't on the map.", x, y));
+            }
+        }
+        self.focus = focus;
+        Ok(())
+    }
+
+    fn focused(&self) -> (usize, usize) {
+        self.focus.unwrap_or_else(|| self.guard_cell())
+    }
+
+    /// Draws the status, the part of the map around the focus that fits in `view`, and markers on the focused row and column.
+    pub fn render(&self, view: (usize, usize)) -> String {
+        let mut out = String::new();
+        let guard = &self.map.guard;
+        let state = match self.state {
+            State::Walking => "walking",
+            State::Left => "left the map",
+            State::Looping => "looping",
+        };
+        writeln!(out, "Step {}: guard at ({}, {}) facing {:?}, {}. {} cells visited.",
+                 self.steps(), guard.x, guard.y, guard.dir, state, self.visited()).unwrap();
+        let (fx, fy) = self.focused();
+        writeln!(out, "Focus ({}, {}): {}.", fx, fy, describe(&self.map.cells[(fx, fy)])).unwrap();
+
+        let (x0, x1) = window(fx, self.map.cells.width(), view.0);
+        let (y0, y1) = window(fy, self.map.cells.height(), view.1);
+        for y in y0..y1 {
+            out.push(if y == fy { '>' } else { ' ' });
+            for x in x0..x1 {
+                if (x, y) == self.guard_cell() {
+                    out.push(guard_char(guard.dir));
+                } else {
+                    write!(out, "{}", self.map.cells[(x, y)]).unwrap();
+                }
+            }
+            out.push('\n');
+        }
+        writeln!(out, " {}^", " ".repeat(fx - x0)).unwrap();
+        out
+    }
+}
+
+/// The `len` wide slice of `0..total` that keeps `at` as central as it can.
+fn window(at: usize, total: usize, len: usize) -> (usize, usize) {
+    if total <= len {
+        return (0, total);
+    }
+    let start = at.saturating_sub(len / 2).min(total - len);
+    (start, start + len)
+}
+
+fn guard_char(dir: Dir4) -> char {
+    match dir {
+        Dir4::Up => '^',
+        Dir4::Right => '>',
+        Dir4::Down => 'v',
+        Dir4::Left => '<',
+    }
+}
+
+fn describe(cell: &Cell) -> String {
+    match cell {
+        Cell::Unvisited => "unvisited".to_string(),
+        Cell::Crate => "a crate".to_string(),
+        Cell::Obstruction => "an obstruction".to_string(),
+        Cell::Visited(history) => {
+            let dirs: Vec<String> = Dir4::all().filter(|dir| history.has(dir))
+                .map(|dir| format!("{:?}", dir).to_lowercase()).collect();
+            format!("visited going {}", dirs.join(", "))
+        }
+    }
+}
+
+/// Reads commands from `input` and draws the map to `output` after each one, until `q` or the end of the input.
+/// `clear` wipes the terminal before each frame, for when `output` is one.
+pub fn interact(stepper: &mut Stepper, input: impl BufRead, output: &mut impl Write, view: (usize, usize), clear: bool) -> io::Result<()> {
+    let mut message = String::new();
+    let mut lines = input.lines();
+    loop {
+        if clear {
+            write!(output, "\x1b[2J\x1b[H")?;
+        }
+        write!(output, "{}", stepper.render(view))?;
+        if !message.is_empty() {
+            writeln!(output, "{}", message)?;
+        }
+        write!(output, "> ")?;
+        output.flush()?;
+        let Some(line) = lines.next() else {
+            writeln!(output)?;
+            return Ok(());
+        };
+        let line = line?;
+        let words: Vec<&str> = line.split_whitespace().collect();
+        if words.first() == Some(&"q") {
+            return Ok(());
+        }
+        message = match command(stepper, &words) {
+            Ok(message) => message,
+            Err(e) => e,
+        };
+    }
+}
+
+fn command(stepper: &mut Stepper, words: &[&str]) -> Result<String, String> {
+    let number = |word: &str| word.parse::<usize>().map_err(|_| format!("'{}' isn't a number.", word));
+    let message = match words {
+        [] | ["s"] => {
+            if !stepper.step() {
+                return Err("The guard is done; back up with b.".to_string());
+            }
+            String::new()
+        },
+        ["s", n] => {
+            let n = number(n)?;
+            let taken = (0..n).take_while(|_| stepper.step()).count();
+            format!("Took {} of {} steps.", taken, n)
+        },
+        ["b"] => {
+            if !stepper.back() {
+                return Err("Already at the start.".to_string());
+            }
+            String::new()
+        },
+        ["b", n] => {
+            let n = number(n)?;
+            let taken = (0..n).take_while(|_| stepper.back()).count();
+            format!("Backed up {} of {} steps.", taken, n)
+        },
+        ["t"] => format!("Took {} steps.", stepper.run_to_turn()),
+        ["r"] => format!("Took {} steps.", stepper.run()),
+        ["o"] => {
+            let (x, y) = stepper.focused();
+            stepper.toggle_obstruction(x, y)?;
+            String::new()
+        },
+        ["o", x, y] => {
+            stepper.toggle_obstruction(number(x)?, number(y)?)?;
+            String::new()
+        },
+        ["f"] => {
+            stepper.focus(None)?;
+            String::new()
+        },
+        ["f", x, y] => {
+            stepper.focus(Some((number(x)?, number(y)?)))?;
+            String::new()
+        },
+        ["?"] => HELP.to_string(),
+        _ => return Err(format!("Unknown command '{}'. ? lists them.", words.join(" "))),
+    };
+    Ok(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{interact, State, Stepper};
+    use crate::util::parsing;
+
+    fn example() -> Stepper {
+        let lines = parsing::file_into_vec("files/day_06_example.txt").unwrap();
+        Stepper::new(&lines).unwrap()
+    }
+
+    #[test]
+    fn test_render() {
+        let mut stepper = example();
+        stepper.run_to_turn();
+        let expected = "\
+Step 6: guard at (4, 1) facing Right, walking. 6 cells visited.
+Focus (4, 1): visited going up.
+ ....#.....
+>....>....#
+ ....X.....
+ ..#.X.....
+ ....X..#..
+ ....X.....
+ .#..X.....
+ ........#.
+ #.........
+ ......#...
+     ^
+";
+        assert_eq!(stepper.render((10, 10)), expected);
+
+        stepper.focus(Some((8, 9))).unwrap();
+        let expected = "\
+Step 6: guard at (4, 1) facing Right, walking. 6 cells visited.
+Focus (8, 9): unvisited.
+ .....
+ ...#.
+ .....
+>.#...
+    ^
+";
+        assert_eq!(stepper.render((5, 4)), expected);
+    }
+
+    #[test]
+    fn test_steps() {
+        let mut stepper = example();
+        let start = stepper.render((10, 10));
+        assert_eq!(stepper.run(), 55);
+        assert_eq!(stepper.state(), State::Left);
+        assert_eq!(stepper.visited(), 41);
+        assert!(!stepper.step());
+        while stepper.back() {}
+        assert_eq!(stepper.render((10, 10)), start);
+
+        stepper.toggle_obstruction(3, 6).unwrap();
+        stepper.run();
+        assert_eq!(stepper.state(), State::Looping);
+        assert!(stepper.toggle_obstruction(0, 8).is_err());
+        assert!(stepper.toggle_obstruction(10, 0).is_err());
+    }
+
+    #[test]
+    fn test_obstruction_on_path() {
+        let mut stepper = example();
+        let start = stepper.render((10, 10));
+        for _ in 0..3 {
+            stepper.step();
+        }
+        let walked = stepper.render((10, 10));
+
+        // Taking the obstruction away again leaves the cell visited.
+        stepper.toggle_obstruction(4, 5).unwrap();
+        assert_eq!(stepper.visited(), 2);
+        stepper.toggle_obstruction(4, 5).unwrap();
+        assert_eq!(stepper.visited(), 3);
+        assert_eq!(stepper.render((10, 10)), walked);
+
+        // Backing up past the step the guard took from it takes it away.
+        stepper.toggle_obstruction(4, 5).unwrap();
+        while stepper.back() {}
+        assert_eq!(stepper.render((10, 10)), start);
+        stepper.run();
+        assert_eq!(stepper.visited(), 41);
+    }
+
+    #[test]
+    fn test_interact() {
+        let mut stepper = example();
+        let mut output = Vec::new();
+        interact(&mut stepper, "s 3\nt\nb\nf 0 8\nnope\nq\ns\n".as_bytes(), &mut output, (10, 10), false).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Took 3 of 3 steps."));
+        assert!(output.contains("Took 3 steps."));
+        assert!(output.contains("Focus (0, 8): a crate."));
+        assert!(output.contains("Unknown command 'nope'."));
+        assert_eq!(stepper.steps(), 5);
+    }
+}
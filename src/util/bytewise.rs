@@ -19,6 +19,10 @@ impl ByteIterator {
     pub fn len(&self) -> usize {
         self.length as usize
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
 }
 
 impl Iterator for ByteIterator {
@@ -0,0 +1,143 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::num::ParseIntError;
+use std::path::{Path, PathBuf};
+
+/// Something wrong with the input, and where it is.
+/// Lines and columns are stored 1-based, the way an editor shows them.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AocError {
+    pub message: String,
+    pub file: Option<PathBuf>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub snippet: Option<String>,
+}
+
+impl AocError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            file: None,
+            line: None,
+            column: None,
+            snippet: None,
+        }
+    }
+
+    /// `index` is the 0-based index into the input lines, as from `enumerate()`.
+    pub fn at_line(mut self, index: usize) -> Self {
+        self.line = Some(index + 1);
+        self
+    }
+
+    /// `index` is the 0-based char index into the line.
+    pub fn at_column(mut self, index: usize) -> Self {
+        self.column = Some(index + 1);
+        self
+    }
+
+    /// Points at `line` (0-based) and shows its text.
+    pub fn at(self, index: usize, line: &str) -> Self {
+        self.at_line(index).with_snippet(line)
+    }
+
+    pub fn with_snippet(mut self, snippet: &str) -> Self {
+        self.snippet = Some(snippet.to_string());
+        self
+    }
+
+    /// Fills in the snippet from the input when the error has a line but no text for it.
+    pub fn snippet_from(self, lines: &Vec<String>) -> Self {
+        match (self.line, &self.snippet) {
+            (Some(line), None) => match lines.get(line - 1) {
+                Some(text) => self.with_snippet(text),
+                None => self,
+            },
+            _ => self,
+        }
+    }
+
+    /// Records which file the input came from, unless a file is already set.
+    pub fn in_file(mut self, path: &Path) -> Self {
+        if self.file.is_none() {
+            self.file = Some(path.to_path_buf());
+        }
+        self
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (&self.file, self.line, self.column) {
+            (Some(file), Some(line), Some(column)) => write!(f, "{}:{}:{}: ", file.display(), line, column)?,
+            (Some(file), Some(line), None) => write!(f, "{}:{}: ", file.display(), line)?,
+            (Some(file), None, _) => write!(f, "{}: ", file.display())?,
+            (None, Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (None, Some(line), None) => write!(f, "line {}: ", line)?,
+            (None, None, _) => {},
+        }
+        write!(f, "{}", self.message)?;
+        if let Some(snippet) = &self.snippet {
+            let gutter = self.line.map(|line| line.to_string()).unwrap_or_default();
+            write!(f, "\n {} | {}", gutter, snippet)?;
+            if let Some(column) = self.column {
+                write!(f, "\n {} | {}^", " ".repeat(gutter.len()), " ".repeat(column - 1))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Error for AocError {}
+
+impl From<String> for AocError {
+    fn from(message: String) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<&str> for AocError {
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
+
+impl From<ParseIntError> for AocError {
+    fn from(e: ParseIntError) -> Self {
+        Self::new(e.to_string())
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(e: io::Error) -> Self {
+        Self::new(e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use super::AocError;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(AocError::new("Empty input").to_string(), "Empty input");
+
+        let error = AocError::new("Expected a number").at(2, "7: 1 x 3").at_column(5);
+        assert_eq!(error.to_string(), "line 3, column 6: Expected a number\n 3 | 7: 1 x 3\n   |      ^");
+
+        let error = error.in_file(Path::new("files/day_07_input.txt")).in_file(Path::new("other.txt"));
+        assert_eq!(error.to_string(), "files/day_07_input.txt:3:6: Expected a number\n 3 | 7: 1 x 3\n   |      ^");
+    }
+
+    #[test]
+    fn test_snippet_from() {
+        let lines = vec!["3   4".to_string(), "4".to_string()];
+        let error = AocError::new("Expected 2 items").at_line(1).snippet_from(&lines);
+        assert_eq!(error.snippet, Some("4".to_string()));
+        let error = AocError::new("Expected 2 items").at_line(5).snippet_from(&lines);
+        assert_eq!(error.snippet, None);
+    }
+}
@@ -0,0 +1,257 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+use crate::util::error::AocError;
+use crate::util::geom::{Dir4, Dir8, Point};
+
+/// A rectangular grid stored row by row in one `Vec`. Positions are `(x, y)` with `(0, 0)` at the top left.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where T: Clone {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, AocError> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(AocError::new(format!("Grid isn't rectangular: expected {} cells, found {}.", width, row.len())).at_line(y));
+            }
+            cells.extend(row);
+        }
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// Turns each character into a cell with `f`, or fails on a character `f` doesn't know.
+    pub fn parse(lines: &Vec<String>, mut f: impl FnMut(char) -> Option<T>) -> Result<Self, AocError> {
+        Ok(Self::parse_with_markers(lines, |c| f(c).map(|cell| (cell, None::<()>)))?.0)
+    }
+
+    /// Like `parse`, but `f` can also put a marker on the cell, such as a guard or an antenna.
+    /// The markers come back with their positions in reading order.
+    pub fn parse_with_markers<M>(lines: &Vec<String>, mut f: impl FnMut(char) -> Option<(T, Option<M>)>) -> Result<(Self, Vec<((usize, usize), M)>), AocError> {
+        let width = lines.first().ok_or("Empty input")?.chars().count();
+        let mut cells = Vec::with_capacity(width * lines.len());
+        let mut markers = Vec::new();
+        for (y, line) in lines.iter().enumerate() {
+            let len = line.chars().count();
+            if len != width {
+                return Err(AocError::new(format!("Grid isn't rectangular: expected {} cells, found {}.", width, len)).at(y, line));
+            }
+            for (x, c) in line.chars().enumerate() {
+                let (cell, marker) = f(c)
+                    .ok_or_else(|| AocError::new(format!("Unexpected '{}'.", c)).at(y, line).at_column(x))?;
+                cells.push(cell);
+                if let Some(marker) = marker {
+                    markers.push(((x, y), marker));
+                }
+            }
+        }
+        let grid = Self {
+            cells,
+            width,
+            height: lines.len(),
+        };
+        Ok((grid, markers))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        self.contains(x, y).then(|| &self.cells[y as usize * self.width + x as usize])
+    }
+
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            Some(&mut self.cells[y as usize * self.width + x as usize])
+        } else {
+            None
+        }
+    }
+
+    fn offsets<'a>(&'a self, x: usize, y: usize, offsets: impl Iterator<Item = Point<isize>> + 'a) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets
+            .map(move |offset| (x as isize + offset.x, y as isize + offset.y))
+            .filter(|&(x, y)| self.contains(x, y))
+            .map(|(x, y)| (x as usize, y as usize))
+    }
+
+    /// The positions up, right, down and left of `(x, y)` that are inside the grid.
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(x, y, Dir4::all().map(Dir4::offset))
+    }
+
+    /// The positions around `(x, y)`, diagonals included, that are inside the grid, clockwise from up.
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(x, y, Dir8::all().map(Dir8::offset))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` because `chunks` can't take 0; a zero-width grid has no cells to chunk anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1)).take(self.height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every cell with its position, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells.iter().enumerate().map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self
+    where T: Clone {
+        Self {
+            cells: self.columns().flatten().cloned().collect(),
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    /// Turns the grid a quarter turn clockwise.
+    pub fn rotate(&self) -> Self
+    where T: Clone {
+        let cells = (0..self.width)
+            .flat_map(|x| (0..self.height).rev().map(move |y| (x, y)))
+            .map(|(x, y)| self[(x, y)].clone())
+            .collect();
+        Self {
+            cells,
+            width: self.height,
+            height: self.width,
+        }
+    }
+}
+
+/// Unchecked access, for positions already known to be inside the grid.
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(x < self.width, "x = {} is outside a grid {} wide", x, self.width);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        assert!(x < self.width, "x = {} is outside a grid {} wide", x, self.width);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+
+    fn lines(text: &str) -> Vec<String> {
+        text.split("\n").map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let (grid, markers) = Grid::parse_with_markers(&lines("..#\n.^.\n#.>"), |c| match c {
+            '.' => Some(('.', None)),
+            '#' => Some(('#', None)),
+            '^' | '>' => Some(('.', Some(c))),
+            _ => None,
+        }).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(markers, vec![((1, 1), '^'), ((2, 2), '>')]);
+        assert_eq!(grid.to_string(), "..#\n...\n#..");
+
+        let error = Grid::parse(&lines("..\n.x"), |c| (c == '.').then_some(c)).unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(2)));
+        let error = Grid::parse(&lines("..\n."), Some).unwrap_err();
+        assert_eq!(error.line, Some(2));
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+    }
+
+    #[test]
+    fn test_get_and_neighbors() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        assert_eq!(grid.get(2, 1), Some(&6));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(-1, 0), None);
+        assert_eq!(grid[(1, 0)], 2);
+        *grid.get_mut(0, 1).unwrap() = 7;
+        assert_eq!(grid[(0, 1)], 7);
+
+        assert_eq!(grid.neighbors4(0, 0).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors4(1, 1).count(), 3);
+        assert_eq!(grid.neighbors8(1, 0).collect::<Vec<_>>(), vec![(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&[1, 2, 3][..], &[4, 5, 6][..]]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &5)));
+
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(grid.rotate().to_string(), "41\n52\n63");
+        assert_eq!(grid.rotate().rotate().rotate().rotate(), grid);
+        assert_eq!(grid.map(|n| n * 2).row(1), &[8, 10, 12]);
+    }
+}
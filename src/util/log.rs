@@ -0,0 +1,115 @@
+use std::env;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// How much to say, from least to most.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Level {
+    Info = 1,
+    Debug = 2,
+    Trace = 3,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!("Log level must be info, debug or trace, not '{}'.", s)),
+        }
+    }
+}
+
+impl Level {
+    pub fn name(self) -> &'static str {
+        match self {
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+/// The environment variable that sets the level when no flag does, e.g. `AOC_LOG=trace`.
+pub const ENV_VAR: &str = "AOC_LOG";
+
+/// 0 is off.
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+
+/// Whether logging is built in at all: always in debug builds, and in release builds with `--features log`.
+/// When it isn't, `enabled` is a constant `false` and the macros compile away, arguments included.
+pub const COMPILED: bool = cfg!(any(debug_assertions, feature = "log"));
+
+pub fn set_level(level: Option<Level>) {
+    LEVEL.store(level.map_or(0, |level| level as u8), Ordering::Relaxed);
+}
+
+/// Sets the level from `AOC_LOG`, if it's set to something sensible.
+pub fn init_from_env() -> Result<(), String> {
+    match env::var(ENV_VAR) {
+        Ok(value) if !value.is_empty() => {
+            set_level(Some(value.parse()?));
+            Ok(())
+        },
+        _ => Ok(()),
+    }
+}
+
+#[inline(always)]
+pub fn enabled(level: Level) -> bool {
+    COMPILED && LEVEL.load(Ordering::Relaxed) >= level as u8
+}
+
+/// Logs to stderr, so answers on stdout stay clean: `log!(Level::Debug, "{} candidates", n)`.
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::util::log::enabled($level) {
+            eprintln!("[{}] {}", $level.name(), format_args!($($arg)+));
+        }
+    };
+}
+pub(crate) use log;
+
+/// Something worth knowing while working on a day, like a count or a summary per step.
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::util::log::log!($crate::util::log::Level::Debug, $($arg)+) };
+}
+pub(crate) use debug;
+
+/// The very chatty kind: every step, whole grids.
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::util::log::log!($crate::util::log::Level::Trace, $($arg)+) };
+}
+pub(crate) use trace;
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use super::{enabled, set_level, Level};
+
+    #[test]
+    fn test_levels() {
+        assert_eq!("TRACE".parse::<Level>(), Ok(Level::Trace));
+        assert!("loud".parse::<Level>().is_err());
+
+        set_level(Some(Level::Debug));
+        assert!(enabled(Level::Info));
+        assert!(enabled(Level::Debug));
+        assert!(!enabled(Level::Trace));
+
+        // Arguments of a disabled level aren't even evaluated.
+        let evaluated = Cell::new(false);
+        let note = || {
+            evaluated.set(true);
+            "expensive"
+        };
+        trace!("{}", note());
+        assert!(!evaluated.get());
+        debug!("{}", note());
+        assert!(evaluated.get());
+        set_level(None);
+    }
+}
@@ -0,0 +1,13 @@
+pub mod error;
+pub mod geom;
+pub mod grid;
+pub mod log;
+pub mod parsing;
+pub mod png;
+pub mod rng;
+pub mod span;
+#[cfg(test)]
+pub(crate) mod testing;
+pub mod vecstuff;
+pub mod bitfutz;
+pub mod bytewise;
@@ -0,0 +1,358 @@
+use std::io::{self, Read};
+use std::fs;
+use std::path::Path;
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+use crate::util::error::AocError;
+
+/// How `load_lines` cleans up input beyond the line endings and BOM it always handles.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct LoadOptions {
+    /// Drop spaces and tabs at the end of every line, as editors sometimes leave them.
+    pub trim_trailing: bool,
+}
+
+/// Reads the lines of a file, or of stdin when `path` is `-`, with the defaults.
+pub fn file_into_vec<P: AsRef<Path>>(path: P) -> Result<Vec<String>, AocError> {
+    load_lines(path.as_ref(), LoadOptions::default())
+}
+
+pub fn load_lines(path: &Path, options: LoadOptions) -> Result<Vec<String>, AocError> {
+    let bytes = if path == Path::new("-") {
+        let mut bytes = Vec::new();
+        io::stdin().read_to_end(&mut bytes)?;
+        bytes
+    } else {
+        fs::read(path)?
+    };
+    bytes_into_lines(&bytes, options)
+}
+
+/// Splits on `\n`, accepting `\r\n` too, and drops a leading byte order mark.
+/// A line that isn't UTF-8 is an error rather than being skipped.
+pub fn bytes_into_lines(bytes: &[u8], options: LoadOptions) -> Result<Vec<String>, AocError> {
+    let bytes = bytes.strip_prefix("\u{feff}".as_bytes()).unwrap_or(bytes);
+    let bytes = bytes.strip_suffix(b"\n").unwrap_or(bytes);
+    if bytes.is_empty() {
+        return Ok(Vec::new());
+    }
+    bytes.split(|&b| b == b'\n').enumerate().map(|(index, line)| {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        let line = std::str::from_utf8(line).map_err(|e| {
+            let valid = String::from_utf8_lossy(&line[..e.valid_up_to()]);
+            AocError::new(format!("Line isn't valid UTF-8 (byte 0x{:02x}).", line[e.valid_up_to()]))
+                .at(index, &String::from_utf8_lossy(line))
+                .at_column(valid.chars().count())
+        })?;
+        Ok(if options.trim_trailing { line.trim_end_matches([' ', '\t']) } else { line }.to_string())
+    }).collect()
+}
+
+pub fn whitepsace_split(lines: Vec<String>) -> Vec<Vec<String>> {
+    lines.iter().map(|line| {
+        line.split_whitespace().map(String::from).collect()
+    }).collect()
+}
+
+pub fn comma_split(lines: &Vec<String>) -> Vec<Vec<String>> {
+    generic_split(lines, ",")
+}
+
+pub fn pipe_split(lines: &Vec<String>) -> Vec<Vec<String>> {
+    generic_split(lines, "|")
+}
+
+pub fn colon_split(lines: &Vec<String>) -> Vec<Vec<String>> {
+    generic_split(lines, ":")
+}
+
+pub fn generic_split(lines: &Vec<String>, on: &str) -> Vec<Vec<String>> {
+    lines.iter().map(|line| {
+        line.split(on).map(String::from).collect()
+    }).collect()
+}
+
+pub fn transpose<T: Debug + Clone>(vec: &Vec<Vec<T>>) -> Result<Vec<Vec<T>>, AocError> {
+    let size: usize = vec.len();
+    if size == 0 {
+        return Err(AocError::new("Array is empty"))
+    }
+    let n: usize = vec[0].len();
+    let mut transposed: Vec<Vec<T>> = Vec::with_capacity(n);
+    for _ in 0..n {
+        transposed.push(Vec::with_capacity(size));
+    }
+    for (i, sub_vec) in vec.iter().enumerate() {
+        let sub_vec_len = sub_vec.len();
+        if sub_vec_len != n {
+            return Err(AocError::new(
+                format!("Item {} in vec has len {} when it should be {}.", i, sub_vec_len, n),
+            ).at_line(i))
+        }
+        for (j, _) in sub_vec.iter().enumerate() {
+            transposed[j].push(vec[i][j].clone());
+        }
+    }
+    Ok(transposed)
+}
+
+pub fn unzip_2<T: Debug + Clone>(lines: Vec<Vec<T>>) -> Result<(Vec<T>, Vec<T>), AocError> {
+    let n: usize = 2;
+
+    let mut a = Vec::with_capacity(lines.len());
+    let mut b = Vec::with_capacity(lines.len());
+    for (idx, line) in lines.iter().enumerate() {
+        if line.len() != n {
+            return Err(AocError::new(
+                format!("All lines must have {} items, but one with {} was found: {:?}", n, line.len(), line)
+            ).at_line(idx))
+        }
+        a.push(line[0].clone());
+        b.push(line[1].clone());
+    }
+
+    Ok((a, b))
+}
+
+pub fn convert_strings<T: std::str::FromStr>(vec: &Vec<String>) -> Result<Vec<T>, T::Err> {
+    vec.iter()
+        .map(|string| string.parse::<T>())
+        .collect()
+}
+
+pub fn convert_strings_matrix<T: std::str::FromStr>(vec: &Vec<Vec<String>>) -> Result<Vec<Vec<T>>, T::Err> {
+    vec.iter()
+        .map(|inner| {
+            convert_strings::<T>(inner)
+        }).collect()
+}
+
+/// Splits `line` on `on`, or on whitespace when `on` is `None`, and parses every field.
+/// `index` is the line's 0-based index, used to point at a field that doesn't parse.
+pub fn parse_row<T: FromStr>(index: usize, line: &str, on: Option<&str>) -> Result<Vec<T>, AocError>
+where T::Err: Display {
+    let fields: Vec<&str> = match on {
+        Some(on) => line.split(on).collect(),
+        None => line.split_whitespace().collect(),
+    };
+    fields.into_iter().map(|field| {
+        let trimmed = field.trim();
+        trimmed.parse::<T>().map_err(|e| {
+            // Every field is a slice of `line`, so its offset is where it starts.
+            let offset = trimmed.as_ptr() as usize - line.as_ptr() as usize;
+            AocError::new(format!("Couldn't parse '{}': {}", trimmed, e))
+                .at(index, line)
+                .at_column(line[..offset].chars().count())
+        })
+    }).collect()
+}
+
+/// `parse_row` for every line.
+pub fn parse_rows<T: FromStr>(lines: &Vec<String>, on: Option<&str>) -> Result<Vec<Vec<T>>, AocError>
+where T::Err: Display {
+    lines.iter().enumerate()
+        .map(|(idx, line)| parse_row(idx, line, on))
+        .collect()
+}
+
+/// A run of non-blank lines, with trailing whitespace and any `\r` trimmed off.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Section<'a> {
+    /// 0-based index of the first line in the input.
+    pub start: usize,
+    pub lines: Vec<&'a str>,
+}
+
+impl Section<'_> {
+    /// 0-based index in the input of the section's `i`th line.
+    pub fn index(&self, i: usize) -> usize {
+        self.start + i
+    }
+}
+
+/// Splits the input on blank lines. Several blank lines in a row, or at either end, don't make empty sections.
+pub fn sections(lines: &Vec<String>) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    let mut current: Option<Section> = None;
+    for (idx, line) in lines.iter().enumerate() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            sections.extend(current.take());
+        } else {
+            current.get_or_insert_with(|| Section { start: idx, lines: Vec::new() }).lines.push(line);
+        }
+    }
+    sections.extend(current);
+    sections
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RecordSize {
+    /// Every record has exactly this many lines.
+    Fixed(usize),
+    /// Records are as long as the runs of non-blank lines.
+    Variable,
+}
+
+/// Blank-line separated records. A `Fixed` record of the wrong length is an error pointing at where it starts.
+pub fn records(lines: &Vec<String>, size: RecordSize) -> impl Iterator<Item = Result<Section<'_>, AocError>> {
+    sections(lines).into_iter().map(move |section| match size {
+        RecordSize::Fixed(n) if section.lines.len() != n => {
+            Err(AocError::new(format!("Expected a record of {} line(s), found {}.", n, section.lines.len()))
+                .at(section.start, section.lines[0]))
+        },
+        _ => Ok(section),
+    })
+}
+
+/// Matches `line` against a template where each `{}` stands for a field, and returns each field's text
+/// with the char column it starts at. `expected` is how many fields the caller wants.
+/// A field runs up to the first match of the literal text after it, or to the end of the line.
+pub fn scan_fields<'a>(template: &str, line: &'a str, expected: usize) -> Result<Vec<(usize, &'a str)>, AocError> {
+    let column = |pos: usize| line[..pos].chars().count();
+    let literals: Vec<&str> = template.split("{}").collect();
+    if literals.len() - 1 != expected {
+        return Err(AocError::new(format!("Template '{}' has {} placeholder(s), not {}.", template, literals.len() - 1, expected)));
+    }
+    let mismatch = |what: &str, pos: usize| {
+        AocError::new(format!("Expected {} to match '{}'.", what, template)).with_snippet(line).at_column(column(pos))
+    };
+
+    let mut pos = 0;
+    if !line.starts_with(literals[0]) {
+        return Err(mismatch(&format!("'{}'", literals[0]), 0));
+    }
+    pos += literals[0].len();
+    let mut fields = Vec::with_capacity(expected);
+    for (i, literal) in literals.iter().enumerate().skip(1) {
+        let end = if literal.is_empty() {
+            if i != literals.len() - 1 {
+                return Err(AocError::new(format!("Template '{}' has two placeholders in a row.", template)));
+            }
+            line.len()
+        } else {
+            match line[pos..].find(literal) {
+                Some(found) => pos + found,
+                None => return Err(mismatch(&format!("'{}' after this", literal), pos)),
+            }
+        };
+        fields.push((column(pos), &line[pos..end]));
+        pos = end + literal.len();
+    }
+    if pos != line.len() {
+        return Err(mismatch("the end of the line", pos));
+    }
+    Ok(fields)
+}
+
+/// Parses one field found by `scan_fields`, pointing at it if it doesn't parse.
+pub fn scan_field<T: FromStr>(line: &str, (column, text): (usize, &str)) -> Result<T, AocError>
+where T::Err: Display {
+    text.trim().parse::<T>().map_err(|e| {
+        AocError::new(format!("Couldn't parse '{}': {}", text.trim(), e)).with_snippet(line).at_column(column)
+    })
+}
+
+/// Scans `line` against a template and parses each `{}` as the matching type:
+/// `scan!(line, "Button A: X+{}, Y+{}", i64, i64)` is a `Result<(i64, i64), AocError>`.
+/// Errors carry the column and the line's text; add the line number with `at_line`.
+#[macro_export]
+macro_rules! scan {
+    ($line:expr, $template:expr, $($t:ty),+ $(,)?) => {{
+        let line: &str = $line;
+        let expected = [$(stringify!($t)),+].len();
+        $crate::util::parsing::scan_fields($template, line, expected).and_then(|fields| {
+            let mut fields = fields.into_iter();
+            // `scan_fields` checked there is one field per type.
+            Ok(($($crate::util::parsing::scan_field::<$t>(line, fields.next().unwrap())?,)+))
+        })
+    }};
+}
+pub use crate::scan;
+
+#[cfg(test)]
+mod tests {
+    use super::{bytes_into_lines, parse_row, parse_rows, records, sections, transpose, unzip_2, LoadOptions, RecordSize};
+
+    #[test]
+    fn test_bytes_into_lines() {
+        let strict = LoadOptions::default();
+        assert_eq!(bytes_into_lines(b"1 2\n\n3 4\n", strict).unwrap(), vec!["1 2", "", "3 4"]);
+        assert_eq!(bytes_into_lines(b"\xef\xbb\xbf1 2\r\n3 4 \r\n", strict).unwrap(), vec!["1 2", "3 4 "]);
+        assert_eq!(bytes_into_lines(b"3 4 \t\r\n5", LoadOptions { trim_trailing: true }).unwrap(), vec!["3 4", "5"]);
+        assert!(bytes_into_lines(b"", strict).unwrap().is_empty());
+
+        let error = bytes_into_lines(b"ok\nab\xffc\nok", strict).unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(3)));
+        assert_eq!(error.snippet, Some("ab\u{fffd}c".to_string()));
+    }
+
+    #[test]
+    fn test_transpose() {
+        let a = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        let a_t_expected = [vec![1, 4], vec![2, 5], vec![3, 6]];
+        let a_t_actual = transpose(&a).expect("Transpose failed!");
+        for i in 0..a.len() {
+            for j in 0..a[0].len() {
+                assert_eq!(a[i][j], a_t_actual[j][i]);
+                assert_eq!(a_t_actual[j][i], a_t_expected[j][i])
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_rows() {
+        let lines = vec!["3   4".to_string(), "4 3".to_string()];
+        assert_eq!(parse_rows::<i32>(&lines, None).unwrap(), vec![vec![3, 4], vec![4, 3]]);
+        assert_eq!(parse_row::<u32>(0, "75,47, 61", Some(",")).unwrap(), vec![75, 47, 61]);
+
+        let error = parse_row::<u32>(4, "75,4x,61", Some(",")).unwrap_err();
+        assert_eq!((error.line, error.column), (Some(5), Some(4)));
+        assert_eq!(error.snippet, Some("75,4x,61".to_string()));
+
+        let error = parse_rows::<i32>(&vec!["1 2".to_string(), "1  -".to_string()], None).unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(4)));
+
+        let error = unzip_2(vec![vec![1, 2], vec![3]]).unwrap_err();
+        assert_eq!(error.line, Some(2));
+    }
+
+    #[test]
+    fn test_sections() {
+        let lines: Vec<String> = "\n47|53  \r\n97|13\n\n \n75,47\r\n\n\n".split("\n").map(|x| x.to_string()).collect();
+        let sections = sections(&lines);
+        assert_eq!(sections.len(), 2);
+        assert_eq!((sections[0].start, sections[0].lines.clone()), (1, vec!["47|53", "97|13"]));
+        assert_eq!((sections[1].index(0), sections[1].lines.clone()), (5, vec!["75,47"]));
+    }
+
+    #[test]
+    fn test_records() {
+        let lines: Vec<String> = "a\nb\n\nc\nd\n\ne".split("\n").map(|x| x.to_string()).collect();
+        assert_eq!(records(&lines, RecordSize::Variable).count(), 3);
+        let fixed: Vec<_> = records(&lines, RecordSize::Fixed(2)).collect();
+        assert!(fixed[0].is_ok() && fixed[1].is_ok());
+        let error = fixed[2].clone().unwrap_err();
+        assert_eq!((error.line, error.snippet), (Some(7), Some("e".to_string())));
+    }
+
+    #[test]
+    fn test_scan() {
+        assert_eq!(scan!("Button A: X+94, Y+-34", "Button A: X+{}, Y+{}", i64, i64), Ok((94, -34)));
+        assert_eq!(scan!("mul(2,4)", "mul({},{})", u64, u64), Ok((2, 4)));
+        assert_eq!(scan!("190: 10 19", "{}: {}", i64, String), Ok((190, "10 19".to_string())));
+        assert_eq!(scan!("42", "{}", u8), Ok((42,)));
+
+        let error = scan!("Button A: X+94, Y+3x", "Button A: X+{}, Y+{}", i64, i64).unwrap_err();
+        assert_eq!(error.column, Some(19));
+        assert_eq!(error.snippet, Some("Button A: X+94, Y+3x".to_string()));
+        let error = scan!("Button B: X+94, Y+34", "Button A: X+{}, Y+{}", i64, i64).unwrap_err();
+        assert_eq!(error.column, Some(1));
+        let error = scan!("mul(2,4", "mul({},{})", u64, u64).unwrap_err();
+        assert_eq!(error.column, Some(7));
+        let error = scan!("mul(2,4)!", "mul({},{})", u64, u64).unwrap_err();
+        assert_eq!(error.column, Some(9));
+        assert!(scan!("mul(2,4)", "mul({},{})", u64).is_err());
+        assert!(scan!("24", "{}{}", u64, u64).is_err());
+    }
+}
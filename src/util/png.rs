@@ -0,0 +1,170 @@
+/// Encodes 8-bit RGB pixels, row by row, as a PNG.
+///
+/// Each row is stored as its difference from the row above, and the deflate stream only looks for runs of one byte,
+/// which is all it takes for pictures made of flat squares.
+pub fn encode(width: u32, height: u32, rgb: &[u8]) -> Vec<u8> {
+    assert_eq!(rgb.len(), width as usize * height as usize * 3, "expected {}x{} RGB pixels", width, height);
+    let stride = width as usize * 3;
+    let mut filtered = Vec::with_capacity((stride + 1) * height as usize);
+    for y in 0..height as usize {
+        let row = &rgb[y * stride..(y + 1) * stride];
+        if y == 0 {
+            filtered.push(0);
+            filtered.extend_from_slice(row);
+        } else {
+            // Filter type 2, "up".
+            filtered.push(2);
+            let above = &rgb[(y - 1) * stride..y * stride];
+            filtered.extend(row.iter().zip(above).map(|(a, b)| a.wrapping_sub(*b)));
+        }
+    }
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&width.to_be_bytes());
+    header.extend_from_slice(&height.to_be_bytes());
+    // 8 bits per channel, truecolor, deflate, adaptive filtering, no interlace.
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    chunk(&mut png, b"IHDR", &header);
+    chunk(&mut png, b"IDAT", &zlib(&filtered));
+    chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+fn chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+/// Writes bits least significant first, as deflate wants them.
+#[derive(Default)]
+struct Bits {
+    bytes: Vec<u8>,
+    buffer: u64,
+    count: u32,
+}
+
+impl Bits {
+    fn push(&mut self, value: u32, count: u32) {
+        self.buffer |= (value as u64) << self.count;
+        self.count += count;
+        while self.count >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    /// Huffman codes go most significant bit first.
+    fn push_code(&mut self, code: u32, count: u32) {
+        let reversed = code.reverse_bits() >> (32 - count);
+        self.push(reversed, count);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+/// Shortest match length for each length code from 257, and how many extra bits follow it.
+const LENGTHS: [(u32, u32); 29] = [
+    (3, 0), (4, 0), (5, 0), (6, 0), (7, 0), (8, 0), (9, 0), (10, 0), (11, 1), (13, 1), (15, 1), (17, 1),
+    (19, 2), (23, 2), (27, 2), (31, 2), (35, 3), (43, 3), (51, 3), (59, 3), (67, 4), (83, 4), (99, 4), (115, 4),
+    (131, 5), (163, 5), (195, 5), (227, 5), (258, 0),
+];
+
+/// The fixed Huffman code for a literal byte, a length code or the end of the block.
+fn push_symbol(bits: &mut Bits, symbol: u32) {
+    match symbol {
+        0..=143 => bits.push_code(0x30 + symbol, 8),
+        144..=255 => bits.push_code(0x190 + symbol - 144, 9),
+        256..=279 => bits.push_code(symbol - 256, 7),
+        _ => bits.push_code(0xC0 + symbol - 280, 8),
+    }
+}
+
+fn push_run(bits: &mut Bits, length: u32) {
+    let idx = LENGTHS.iter().rposition(|&(base, _)| base <= length).unwrap();
+    let (base, extra) = LENGTHS[idx];
+    push_symbol(bits, 257 + idx as u32);
+    bits.push(length - base, extra);
+    // Distance code 0 is one byte back, with no extra bits.
+    bits.push_code(0, 5);
+}
+
+/// A zlib stream of one fixed-Huffman block, repeating the previous byte wherever it can.
+fn zlib(data: &[u8]) -> Vec<u8> {
+    let mut bits = Bits::default();
+    // Final block, fixed Huffman codes.
+    bits.push(1, 1);
+    bits.push(1, 2);
+    let mut idx = 0;
+    while idx < data.len() {
+        let byte = data[idx];
+        push_symbol(&mut bits, byte as u32);
+        idx += 1;
+        let mut run = data[idx..].iter().take_while(|&&next| next == byte).count();
+        while run >= 3 {
+            let length = run.min(258);
+            push_run(&mut bits, length as u32);
+            idx += length;
+            run -= length;
+        }
+    }
+    push_symbol(&mut bits, 256);
+
+    let mut stream = vec![0x78, 0x01];
+    stream.extend(bits.finish());
+    stream.extend_from_slice(&adler32(data).to_be_bytes());
+    stream
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{adler32, crc32, encode};
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+    }
+
+    #[test]
+    fn test_encode() {
+        let pixels: Vec<u8> = [[255, 0, 0], [255, 0, 0], [0, 0, 255], [0, 0, 255]].concat();
+        let png = encode(2, 2, &pixels);
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..29], &[0, 0, 0, 2, 0, 0, 0, 2, 8, 2, 0, 0, 0]);
+        assert_eq!(&png[png.len() - 12..], &[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xAE, 0x42, 0x60, 0x82]);
+    }
+}
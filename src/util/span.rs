@@ -0,0 +1,121 @@
+use std::cell::RefCell;
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+/// One name under one parent, with every time it was entered added together.
+struct Node {
+    name: &'static str,
+    parent: Option<usize>,
+    total: Duration,
+    count: usize,
+}
+
+#[derive(Default)]
+struct Recorder {
+    enabled: bool,
+    nodes: Vec<Node>,
+    /// The spans currently open, innermost last.
+    open: Vec<usize>,
+}
+
+thread_local! {
+    static RECORDER: RefCell<Recorder> = RefCell::new(Recorder::default());
+}
+
+/// Starts recording spans on this thread. Until then, `enter` only checks a flag.
+pub fn enable() {
+    RECORDER.with(|recorder| recorder.borrow_mut().enabled = true);
+}
+
+/// Times the rest of the enclosing scope under `name`, nested inside whatever span is open.
+/// Entering the same name in the same place again adds to its total instead of making a new entry.
+#[must_use = "the span ends when this is dropped"]
+pub fn enter(name: &'static str) -> Span {
+    RECORDER.with(|recorder| {
+        let mut recorder = recorder.borrow_mut();
+        if !recorder.enabled {
+            return Span { start: None };
+        }
+        let parent = recorder.open.last().copied();
+        let index = match recorder.nodes.iter().position(|node| node.parent == parent && node.name == name) {
+            Some(index) => index,
+            None => {
+                recorder.nodes.push(Node { name, parent, total: Duration::ZERO, count: 0 });
+                recorder.nodes.len() - 1
+            }
+        };
+        recorder.open.push(index);
+        Span { start: Some(Instant::now()) }
+    })
+}
+
+pub struct Span {
+    start: Option<Instant>,
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if let Some(start) = self.start {
+            let elapsed = start.elapsed();
+            RECORDER.with(|recorder| {
+                let mut recorder = recorder.borrow_mut();
+                if let Some(index) = recorder.open.pop() {
+                    recorder.nodes[index].total += elapsed;
+                    recorder.nodes[index].count += 1;
+                }
+            });
+        }
+    }
+}
+
+/// The spans recorded so far as an indented tree, children in the order they were first entered,
+/// each with its total time, how many times it ran and its share of its parent. Clears them afterwards.
+pub fn take_report() -> String {
+    let nodes = RECORDER.with(|recorder| std::mem::take(&mut recorder.borrow_mut().nodes));
+    let mut out = String::new();
+    write_children(&nodes, None, 0, &mut out);
+    out
+}
+
+fn write_children(nodes: &[Node], parent: Option<usize>, depth: usize, out: &mut String) {
+    for (index, node) in nodes.iter().enumerate().filter(|(_, node)| node.parent == parent) {
+        let label = format!("{}{}", "  ".repeat(depth), node.name);
+        write!(out, "{:<40} {:>12} {:>8}", label, format!("{:.2?}", node.total), format!("x{}", node.count)).unwrap();
+        if let Some(parent) = parent {
+            let share = node.total.as_secs_f64() / nodes[parent].total.as_secs_f64().max(f64::MIN_POSITIVE);
+            write!(out, " {:>6.1}%", share * 100.0).unwrap();
+        }
+        out.push('\n');
+        write_children(nodes, Some(index), depth + 1, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{enable, enter, take_report};
+
+    #[test]
+    fn test_tree() {
+        // Not enabled yet, so nothing is kept.
+        drop(enter("ignored"));
+        assert_eq!(take_report(), "");
+
+        enable();
+        {
+            let _outer = enter("part 2");
+            drop(enter("coarse_candidate_obstacles"));
+            for _ in 0..3 {
+                let _inner = enter("does_map_loop");
+            }
+        }
+        drop(enter("part 2"));
+        let report = take_report();
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("part 2 ") && lines[0].ends_with(" x2"), "{}", report);
+        assert!(lines[1].starts_with("  coarse_candidate_obstacles ") && lines[1].contains(" x1 "), "{}", report);
+        assert!(lines[2].starts_with("  does_map_loop ") && lines[2].contains(" x3 "), "{}", report);
+        assert!(lines[2].ends_with('%'));
+        assert_eq!(take_report(), "");
+    }
+}
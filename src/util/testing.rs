@@ -0,0 +1,10 @@
+use std::fs;
+use std::path::PathBuf;
+
+/// A fresh, empty directory under the system's temp dir, for tests that write files. The name keeps parallel tests apart.
+pub(crate) fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("advent_2024_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
@@ -1,6 +1,6 @@
 pub fn vec_without<T: Clone>(vec: &Vec<T>, index: usize) -> Vec<T> {
     vec
-        .into_iter()
+        .iter()
         .enumerate()
         .filter_map(|(idx, value)| {
             match idx == index {
@@ -18,7 +18,7 @@ pub fn center<T>(vec: &Vec<T>) -> &T {
 }
 
 pub fn deep_copy_matrix<T: Clone>(original: &Vec<Vec<T>>) -> Vec<Vec<T>> {
-    original.iter().map(|inner| inner.clone()).collect()
+    original.to_vec()
 }
 
 #[cfg(test)]
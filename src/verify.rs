@@ -0,0 +1,189 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use crate::runner::{self, Part};
+use crate::util::parsing;
+
+pub const DEFAULT_ANSWERS: &str = "files/answers.txt";
+
+/// Recorded answers for the real puzzle inputs, keyed by day and part.
+#[derive(Default, Debug)]
+pub struct AnswerBook {
+    answers: BTreeMap<(u8, Part), String>,
+}
+
+impl AnswerBook {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn Error>> {
+        let path = path.as_ref();
+        let lines = parsing::file_into_vec(path)
+            .map_err(|e| format!("Couldn't read {}: {}", path.display(), e))?;
+        Self::parse(&lines).map_err(|e| format!("{}: {}", path.display(), e).into())
+    }
+
+    /// Reads `day part answer` lines, skipping blank lines and `#` comments.
+    pub fn parse(lines: &Vec<String>) -> Result<Self, String> {
+        let mut book = Self::default();
+        for (idx, line) in lines.iter().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() != 3 {
+                return Err(format!("line {}: expected 'day part answer', found '{}'", idx + 1, line));
+            }
+            let day = fields[0].parse::<u8>()
+                .map_err(|_| format!("line {}: '{}' is not a day", idx + 1, fields[0]))?;
+            let part = fields[1].parse::<Part>()
+                .map_err(|e| format!("line {}: {}", idx + 1, e))?;
+            book.insert(day, part, fields[2]);
+        }
+        Ok(book)
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&String> {
+        self.answers.get(&(day, part))
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, answer: &str) {
+        self.answers.insert((day, part), answer.to_string());
+    }
+
+    /// Adds an answer to the book and appends it to the file at `path`.
+    /// Fails rather than contradict an answer that's already recorded.
+    pub fn record<P: AsRef<Path>>(&mut self, path: P, day: u8, part: Part, answer: &str) -> Result<(), Box<dyn Error>> {
+        match self.get(day, part) {
+            Some(recorded) if recorded == answer => return Ok(()),
+            Some(recorded) => return Err(format!("Day {} part {} already has the answer {}, not {}.", day, part, recorded, answer).into()),
+            None => {},
+        }
+        let path = path.as_ref();
+        let mut file = OpenOptions::new().create(true).append(true).open(path)
+            .map_err(|e| format!("Couldn't open {}: {}", path.display(), e))?;
+        writeln!(file, "{} {} {}", day, part, answer)?;
+        self.insert(day, part, answer);
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Correct,
+    Mismatch { expected: String, actual: String },
+    Missing { actual: String },
+    Panic(String),
+    Error(String),
+}
+
+impl Outcome {
+    pub fn passed(&self) -> bool {
+        matches!(self, Outcome::Correct)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "ok"),
+            Outcome::Mismatch { expected, actual } => write!(f, "MISMATCH: expected {}, got {}", expected, actual),
+            Outcome::Missing { actual } => write!(f, "MISSING: no recorded answer, got {}", actual),
+            Outcome::Panic(message) => write!(f, "PANIC: {}", message),
+            Outcome::Error(message) => write!(f, "ERROR: {}", message),
+        }
+    }
+}
+
+/// Solves both parts of `day` on its real input and compares them against the book.
+pub fn verify_day(book: &AnswerBook, day: u8) -> [(Part, Outcome); 2] {
+    let path = runner::default_input(day);
+    let answers = runner::read_input(&path)
+        .map_err(|e| e.to_string())
+        .map(|lines| runner::catch_panic(|| runner::solve(day, &lines, None).map_err(|e| e.in_file(&path))));
+    [Part::One, Part::Two].map(|part| {
+        let outcome = match &answers {
+            Err(e) => Outcome::Error(e.clone()),
+            Ok(Err(message)) => Outcome::Panic(message.clone()),
+            Ok(Ok(Err(e))) => Outcome::Error(e.to_string()),
+            Ok(Ok(Ok(answers))) => compare(book.get(day, part), answers.get(part)),
+        };
+        (part, outcome)
+    })
+}
+
+pub(crate) fn compare(expected: Option<&String>, actual: Option<&String>) -> Outcome {
+    let actual = actual.cloned().unwrap_or_default();
+    match expected {
+        None => Outcome::Missing { actual },
+        Some(expected) if *expected == actual => Outcome::Correct,
+        Some(expected) => Outcome::Mismatch { expected: expected.clone(), actual },
+    }
+}
+
+/// Verifies every day in `days`, printing one line per part. Returns whether all of them passed.
+pub fn verify(book: &AnswerBook, days: &[u8]) -> bool {
+    let mut failures = 0;
+    for &day in days {
+        for (part, outcome) in verify_day(book, day) {
+            println!("Day {:>2} part {}: {}", day, part, outcome);
+            if !outcome.passed() {
+                failures += 1;
+            }
+        }
+    }
+    if failures == 0 {
+        println!("All {} answers match.", days.len() * 2);
+    } else {
+        println!("{} of {} answers failed.", failures, days.len() * 2);
+    }
+    failures == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use super::{compare, AnswerBook, Outcome};
+    use crate::util::testing::scratch_dir;
+    use crate::runner::Part;
+
+    #[test]
+    fn test_parse_book() {
+        let lines: Vec<String> = "# day part answer\n\n1 1 11\n1 2 31\n  7 2   11387  "
+            .split("\n").map(|x| x.to_string()).collect();
+        let book = AnswerBook::parse(&lines).unwrap();
+        assert_eq!(book.get(1, Part::One).unwrap(), "11");
+        assert_eq!(book.get(1, Part::Two).unwrap(), "31");
+        assert_eq!(book.get(7, Part::Two).unwrap(), "11387");
+        assert!(book.get(7, Part::One).is_none());
+
+        let bad: Vec<String> = vec!["1 3 11".to_string()];
+        assert!(AnswerBook::parse(&bad).is_err());
+        let short: Vec<String> = vec!["1 1".to_string()];
+        assert!(AnswerBook::parse(&short).is_err());
+    }
+
+    #[test]
+    fn test_record() {
+        let dir = scratch_dir("record");
+        let path = dir.join("answers.txt");
+        fs::write(&path, "# day part answer\n1 1 11\n").unwrap();
+        let mut book = AnswerBook::load(&path).unwrap();
+        book.record(&path, 1, Part::Two, "31").unwrap();
+        book.record(&path, 1, Part::Two, "31").unwrap();
+        assert!(book.record(&path, 1, Part::One, "12").is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "# day part answer\n1 1 11\n1 2 31\n");
+        assert_eq!(AnswerBook::load(&path).unwrap().get(1, Part::Two).unwrap(), "31");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_compare() {
+        let expected = "480".to_string();
+        let wrong = "481".to_string();
+        assert_eq!(compare(Some(&expected), Some(&expected)), Outcome::Correct);
+        assert!(!compare(Some(&expected), Some(&wrong)).passed());
+        assert_eq!(compare(None, Some(&wrong)), Outcome::Missing { actual: wrong });
+    }
+}